@@ -3,8 +3,9 @@
 #[ink::contract]
 mod agario_buyin {
     use core::convert::TryInto;
+    use ink::env::call::{build_call, ExecutionInput, Selector};
     use ink::prelude::vec::Vec;
-    use ink::storage::Mapping;
+    use ink::storage::{Lazy, Mapping};
     use ink::H160;
 
     /// Defines the storage of your contract.
@@ -14,12 +15,15 @@ mod agario_buyin {
         /// Administrative fields
         game_admin: H160,
         admin_fee_percentage: u8, // 0-100
+        roles: Mapping<(Role, H160), ()>,
 
         /// Game state management
         game_state: GameState,
         buy_in_amount: Balance,
 
         /// Registration & Timing
+        /// All deadlines and durations are expressed in units of `time_basis`
+        time_basis: TimeBasis,
         registration_deadline: Timestamp,
         min_players: u32,
         max_players: Option<u32>,
@@ -27,9 +31,140 @@ mod agario_buyin {
         /// Game Duration & End Conditions
         game_duration: Option<Timestamp>, // None = no time limit
         game_start_time: Timestamp,
+        settlement_period: Option<Timestamp>, // None = no settlement deadline
+        settlement_deadline: Timestamp,
+
+        /// Late registration (measured from game_start_time)
+        late_registration_period: Option<Timestamp>, // None = no late registration
+        late_buy_in_amount: Option<Balance>, // None = same as buy_in_amount
+
+        /// Re-buys during InProgress
+        rebuy_config: Option<RebuyConfig>, // None = re-buys disabled
+        rebuy_count: u32,
+
+        /// Cash-game mode: players may leave mid-game with their attested value
+        cash_game: bool,
+
+        /// Bounties: share of each buy-in paid to whoever eliminates the player
+        bounty_percentage: u8, // 0-100, 0 = bounties disabled
+        bounty_pool: Balance, // Outstanding bounties, kept out of prize_pool
+
+        /// Alive-set tracking, fed by reported eliminations
+        alive_count: u32,
+        elimination_count: u32,
+        elimination_log: Mapping<(u32, u32), H160>, // (round, elimination order) -> player
+
+        /// Team mode
+        team_config: Option<TeamConfig>, // None = individual game
+        team_sizes: Mapping<(u32, u32), u32>, // (round, team) -> member count
+        team_members: Mapping<(u32, u32, u32), H160>, // (round, team, index) -> player
+
+        /// Multi-stage tournament played as consecutive arena games
+        tournament: Option<Tournament>, // None = no tournament running
+        tournament_count: u32,
+        tournament_entrants: Mapping<(u32, u32, u32), H160>, // (tournament, stage, index) -> player
+        tournament_stage_sizes: Mapping<(u32, u32), u32>,    // (tournament, stage) -> entrants
+        tournament_refunds: Mapping<(u32, u32), (H160, Balance)>, // (tournament, index) -> credit
+
+        /// Sponsorship and guaranteed prize pools
+        sponsors: Mapping<(u32, u32), H160>, // (round, index) -> sponsor
+        sponsor_count: u32,
+        sponsor_contributions: Mapping<(u32, H160), Balance>, // (round, sponsor) -> amount
+        sponsored_amount: Balance,
+        treasury_balance: Balance, // Funds available for guarantee overlays
+        guaranteed_pool: Option<Balance>,
+        guarantee_reserve: Balance, // Reserved from the treasury at start_game
+        overlay: Balance,           // Treasury top-up of the current game
+        total_overlay: Balance,     // Lifetime overlay cost
+
+        /// Progressive jackpot, survives reset_game_state
+        jackpot: Balance,
+        jackpot_config: JackpotConfig,
+        jackpot_contributions: Mapping<u32, JackpotContribution>, // index -> contribution
+        jackpot_contribution_count: u32,
+
+        /// Season leaderboard, survives reset_game_state
+        season: Option<Season>, // None = no season running
+        season_count: u32,
+        season_points: Mapping<(u32, H160), u32>, // (season, player) -> points
+        season_leaderboard: Lazy<Vec<(H160, u32)>>, // top players of the running season, kept out of the root
+        season_carryover: Balance,                  // unpaid season pool moved to the next season
+
+        /// Registration order of the current round, used for refunds
+        roster: Mapping<(u32, u32), H160>, // (round, index) -> player
+        roster_len: u32,
+
+        /// Lifetime statistics per wallet
+        player_stats: Mapping<H160, PlayerStats>,
+
+        /// Skill ratings, updated from placements at settlement
+        ratings: Mapping<H160, u32>,
+        rating_band: Option<(u32, u32)>, // Inclusive (min, max) entry band, None = open
+
+        /// Entry gating of the current game
+        entry_gate: EntryGate,
+        allowlist: Mapping<(u32, H160), ()>, // (round, player), used by `EntryGate::Allowlist`
+
+        /// Matchmaking queues per stake tier
+        queue_tiers: Mapping<Balance, QueueTier>, // stake -> tier settings
+        queue_entries: Mapping<(Balance, u32), H160>, // (stake, position) -> player
+        queue_bounds: Mapping<Balance, (u32, u32)>,   // stake -> (head, tail) positions
+        queue_sizes: Mapping<Balance, u32>,           // stake -> players waiting
+        queued: Mapping<H160, (Balance, u32)>,        // player -> (stake, position)
+
+        /// Seat tickets (ERC-721 style), burned when the round ends
+        tickets: Mapping<TicketId, Ticket>,
+        ticket_balances: Mapping<H160, u32>,
+        ticket_approvals: Mapping<TicketId, H160>,
+        ticket_operators: Mapping<(H160, H160), ()>, // (owner, operator)
+        next_ticket_id: TicketId,
+
+        /// Non-transferable trophies minted to top placements at settlement
+        trophy_places: u32, // Placements receiving a trophy, 0 = disabled
+        trophies: Mapping<u32, Trophy>,
+        trophy_count: u32,
+        player_trophies: Mapping<(H160, u32), u32>, // (owner, index) -> trophy id
+        player_trophy_counts: Mapping<H160, u32>,
+
+        /// Achievement badges, (player, badge) -> round awarded
+        badges: Mapping<(H160, Badge), u32>,
+        biggest_pool: Balance, // Largest pool settled so far
+
+        /// Seats offered for sale before the game begins
+        seat_offers: Mapping<(u32, H160), (H160, Balance)>, // (round, holder) -> (buyer, price)
+
+        /// Referral program
+        referral_percentage: u8, // Share of a referred player's slice of the admin fee
+        referrers: Mapping<H160, H160>, // player -> referrer, recorded once
+        referral_counts: Mapping<H160, u32>, // referrer -> players referred
+        referral_earnings: Mapping<H160, Balance>, // referrer -> lifetime earnings
+
+        /// Balances credited to accounts, withdrawn with `claim`
+        claimable: Mapping<H160, Balance>,
+        token_claimable: Mapping<(H160, H160), Balance>, // (token, account) -> balance
+
+        /// Currency buy-ins and payouts of the current game are made in
+        currency: Currency,
+        pegged_buy_in: Option<PeggedBuyIn>, // Buy-in priced in a reference currency
+
+        /// Admin fees held by the contract until a treasurer withdraws them
+        accrued_fees: Mapping<Currency, Balance>,
+        lifetime_fees: Mapping<Currency, Balance>,
+
+        /// Fee beneficiaries, the pending split applies from the next round on
+        fee_split: Option<FeeSplit>,
+        round_fee_split: Option<FeeSplit>, // Split of the current round, None = all to admin
+
+        /// Entry fees paid on top of the stake
+        entry_fee: Balance, // Per seat, 0 = percentage admin fee instead
+        keep_entry_fee_on_refund: bool,
+        entry_fees: Balance,       // Fee ledger of the current round
+        total_entry_fees: Balance, // Lifetime entry fees accrued
 
         /// Players & Prize Pool
-        players: Mapping<H160, ()>,
+        /// Incremented on every start_game so records from earlier rounds are ignored
+        current_round: u32,
+        players: Mapping<H160, PlayerInfo>,
         player_count: u32,
         prize_pool: Balance,
     }
@@ -45,6 +180,358 @@ mod agario_buyin {
         WaitingForResults, // NEW: Game ended, waiting for winner submission
     }
 
+    /// Unit in which registration windows, game durations and settlement deadlines are measured
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum TimeBasis {
+        /// Block timestamps in milliseconds, configured in minutes
+        #[default]
+        Timestamp,
+        /// Block numbers, configured in blocks
+        Blocks,
+    }
+
+    impl TimeBasis {
+        /// Convert a configured period into units of this basis
+        fn period_to_units(self, period: u32) -> Timestamp {
+            match self {
+                // Convert minutes to milliseconds
+                TimeBasis::Timestamp => (period as u64).saturating_mul(60).saturating_mul(1000),
+                TimeBasis::Blocks => period as u64,
+            }
+        }
+    }
+
+    /// Parameters for starting a new game
+    #[derive(Debug, Default, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct GameConfig {
+        pub buy_in: Balance,
+        pub min_players: u32,
+        pub max_players: Option<u32>,
+        pub time_basis: TimeBasis,
+        /// Registration window (minutes or blocks, depending on `time_basis`)
+        pub registration_period: u32,
+        /// Game duration (minutes or blocks), None = no time limit
+        pub game_duration: Option<u32>,
+        /// Time allowed for winner submission once the game ends (minutes or blocks)
+        pub settlement_period: Option<u32>,
+        /// Window after the game begins in which players may still join (minutes or blocks)
+        pub late_registration_period: Option<u32>,
+        /// Buy-in for late entrants, None = same as `buy_in`
+        pub late_buy_in: Option<Balance>,
+        /// Re-buy amount and limits, None = re-buys disabled
+        pub rebuy: Option<RebuyConfig>,
+        /// Cash game: players can cash out mid-game instead of waiting for settlement
+        pub cash_game: bool,
+        /// Percentage of each buy-in that goes into the player's bounty (0 = disabled)
+        pub bounty_percentage: u8,
+        /// Team setup, None = individual game
+        pub teams: Option<TeamConfig>,
+        /// Minimum prize pool topped up from the treasury when the game begins
+        pub guaranteed_pool: Option<Balance>,
+        /// Inclusive (min, max) rating required to deposit, None = open to all ratings
+        pub rating_band: Option<(u32, u32)>,
+        /// Who may deposit, `EntryGate::Open` = anyone
+        pub entry_gate: EntryGate,
+        /// Currency of buy-ins and payouts
+        pub currency: Currency,
+        /// Buy-in priced in a reference currency through an oracle, overrides `buy_in`
+        pub pegged_buy_in: Option<PeggedBuyIn>,
+        /// Fee paid on top of the stake (`buy_in`) at deposit; when non-zero it replaces the
+        /// percentage admin fee and the whole stake goes to the prize pool
+        pub entry_fee: Balance,
+        /// Keep entry fees when the game is refunded instead of returning them
+        pub keep_entry_fee_on_refund: bool,
+    }
+
+    /// Per-player record for the round the player registered in
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct PlayerInfo {
+        pub round: u32,
+        /// Joined after the game had already begun
+        pub late_entry: bool,
+        /// Number of paid entries (initial buy-in plus re-buys)
+        pub entries: u32,
+        /// Key allowed to act on the player's behalf during the round
+        pub session_key: Option<H160>,
+        /// Cash-out requested, waiting for the game server to attest the value
+        pub cash_out_requested: bool,
+        /// Bounty paid to whoever eliminates this player
+        pub bounty: Balance,
+        /// Bounty already paid to eliminators, kept back on refund
+        pub bounty_paid: Balance,
+        /// Position in the elimination order (1 = first out), None = still alive
+        pub elimination_order: Option<u32>,
+        /// Team joined in team games
+        pub team: Option<u32>,
+        /// Server-reported contribution used to split the team's share
+        pub contribution: u32,
+        /// Total paid into the game (buy-in plus re-buys), returned on refund
+        pub paid: Balance,
+        /// Account that paid the buy-in on the player's behalf, None = the player
+        pub payer: Option<H160>,
+        /// Ticket representing the seat
+        pub ticket: Option<TicketId>,
+        /// Entry fee paid on top of the stake
+        pub entry_fee: Balance,
+        /// Referrer earning a share of the seat's admin fee, cleared when the seat changes hands
+        pub referrer: Option<H160>,
+    }
+
+    /// Seat ticket, held by the player entitled to the seat
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Ticket {
+        pub owner: H160,
+        pub round: u32,
+        /// Amount paid for the seat
+        pub buy_in: Balance,
+    }
+
+    /// Lifetime statistics of a wallet
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct PlayerStats {
+        pub games_played: u32,
+        /// First places
+        pub wins: u32,
+        pub total_buy_ins: Balance,
+        /// Prizes, cash-outs, bounties and jackpots
+        pub total_winnings: Balance,
+        pub refunds: u32,
+        pub total_refunded: Balance,
+    }
+
+    /// How a team's share is divided among its members
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum TeamSplit {
+        #[default]
+        Equal,
+        /// Proportional to server-reported contribution
+        Contribution,
+    }
+
+    /// Team game configuration
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct TeamConfig {
+        pub team_count: u32,
+        pub min_team_size: u32,
+        pub max_team_size: u32,
+        pub split: TeamSplit,
+    }
+
+    /// Roles that can be granted by the admin
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum Role {
+        /// Authorized game server attesting in-game results
+        GameServer,
+        /// Allowed to withdraw accrued fees
+        Treasurer,
+    }
+
+    /// Re-buy limits for a game
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct RebuyConfig {
+        pub amount: Balance,
+        pub max_per_player: u32,
+        pub max_per_game: u32,
+    }
+
+    /// Tournament bracket state
+    ///
+    /// Stage 0 is registration through the normal `deposit` flow. Every later stage seats its
+    /// entrants into consecutive arena games, whose top placements advance to the next stage.
+    #[derive(Debug, Default, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Tournament {
+        pub id: u32,
+        pub table_size: u32,
+        pub advance_per_table: u32,
+        /// Percentages paid to the final table placements
+        pub prize_structure: Vec<u8>,
+        pub pool: Balance,
+        pub stage: u32,
+        /// Entrants of the current stage already seated at a table
+        pub seated: u32,
+        pub table_running: bool,
+        pub final_table: bool,
+        /// Registration tickets `[first, end)`, burned once the tournament ends
+        pub tickets: (TicketId, TicketId),
+        /// Fee split captured at registration, used by every table
+        pub fee_split: Option<FeeSplit>,
+        /// Treasury overlay included in `pool`, returned if the tournament is cancelled
+        pub overlay: Balance,
+        /// Number of registration refund credits recorded when registration closed
+        pub refund_count: u32,
+    }
+
+    /// Progressive jackpot settings
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct JackpotConfig {
+        /// Percentage of every settled pool moved from the admin cut into the jackpot
+        pub percentage: u8,
+        /// Jackpot is won by the first placed winner of a game with at least this many players
+        pub min_players: Option<u32>,
+    }
+
+    /// A single jackpot contribution
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct JackpotContribution {
+        pub round: u32,
+        pub amount: Balance,
+    }
+
+    /// Season with placement points and an end-of-season payout
+    #[derive(Debug, Default, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Season {
+        pub id: u32,
+        /// Block timestamps (ms) bounding the season
+        pub start: Timestamp,
+        pub end: Timestamp,
+        /// Points awarded per placement of `submit_winners` (index 0 = first place)
+        pub points_table: Vec<u32>,
+        /// Percentage of every settled pool moved from the admin cut into the season pool
+        pub fee_percentage: u8,
+        /// Season pool percentages paid to the top of the leaderboard
+        pub payout_percentages: Vec<u8>,
+        pub pool: Balance,
+    }
+
+    /// Currency a game is played for
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum Currency {
+        /// Native token, transferred with the call
+        #[default]
+        Native,
+        /// ink! token contract with the PSP22-style messages `transfer(to, value)` and
+        /// `transfer_from(from, to, value)`, both returning `Result<(), E>` for a fieldless
+        /// error enum `E`; pulled with `transfer_from` after approval
+        ///
+        /// Solidity ABI (ERC-20) tokens are not supported.
+        Token(H160),
+    }
+
+    /// Buy-in expressed in a reference currency and converted with a price oracle
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct PeggedBuyIn {
+        /// Contract implementing `price_oracle::PriceOracle`
+        pub oracle: H160,
+        /// Buy-in in reference currency units
+        pub reference_amount: u128,
+        /// Oldest oracle update accepted, in milliseconds
+        pub max_price_age: u64,
+        /// None = convert once at game start, Some = re-quote on every deposit and accept
+        /// amounts within this tolerance in basis points
+        pub slippage_bps: Option<u16>,
+    }
+
+    /// Split of the admin fee between beneficiaries, in basis points summing to 10000
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct FeeSplit {
+        /// Platform treasury, also receives rounding remainders
+        pub treasury: H160,
+        pub treasury_bps: u16,
+        /// Arena host
+        pub host: H160,
+        pub host_bps: u16,
+        /// Development fund
+        pub dev_fund: H160,
+        pub dev_fund_bps: u16,
+        /// Charity, None = no charity share
+        pub charity: Option<H160>,
+        pub charity_bps: u16,
+    }
+
+    /// Who may deposit into a game
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum EntryGate {
+        /// Any address
+        #[default]
+        Open,
+        /// Addresses on the admin-managed allowlist
+        Allowlist,
+        /// Addresses proven against this Merkle root of keccak256(address) leaves
+        MerkleRoot([u8; 32]),
+        /// Anyone presenting the preimage of this keccak256 hash
+        Passcode([u8; 32]),
+    }
+
+    /// Credential presented at deposit into a gated game
+    #[derive(Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum EntryCredential {
+        /// Sibling hashes from the caller's leaf up to the root
+        MerkleProof(Vec<[u8; 32]>),
+        /// Passcode preimage
+        Passcode(Vec<u8>),
+    }
+
+    /// Matchmaking settings of a stake tier
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct QueueTier {
+        /// Players seated in each game formed from the queue
+        pub players_per_game: u32,
+        /// Maximum rating distance from the longest waiting player of a match, None = ignore ratings
+        pub rating_width: Option<u32>,
+        /// Game duration in minutes, None = no time limit
+        pub game_duration: Option<u32>,
+    }
+
+    /// Trophy minted for a top placement, cannot be transferred
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Trophy {
+        pub owner: H160,
+        pub round: u32,
+        /// 1 = first place
+        pub placement: u32,
+        pub pool: Balance,
+        pub reason: GameEndReason,
+    }
+
+    /// Achievement badges earned from lifetime statistics
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum Badge {
+        FirstWin,
+        TenWins,
+        /// Won the largest pool settled so far
+        BiggestPool,
+    }
+
     /// Game end reason enumeration
     #[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -75,11 +562,150 @@ mod agario_buyin {
         TooFewPlayers,
         MismatchedData,
         InvalidPercentages,
+        PlayerNotRegistered,
+        RebuyNotAllowed,
+        RebuyLimitReached,
+        MissingRole,
+        NotAuthorized,
+        NotCashGame,
+        CashOutNotRequested,
+        InsufficientPrizePool,
+        InvalidElimination,
+        NothingToClaim,
+        InvalidTeamConfig,
+        TeamRequired,
+        NotTeamGame,
+        InvalidTeam,
+        TeamFull,
+        NoTournament,
+        TournamentInProgress,
+        InvalidTournamentConfig,
+        InvalidRound,
+        NoValueTransferred,
+        InsufficientTreasury,
+        JackpotEmpty,
+        NoSeason,
+        SeasonActive,
+        SeasonNotOver,
+        InvalidSeasonConfig,
+        RatingOutOfBand,
+        InvalidRatingBand,
+        NoQueueTier,
+        InvalidQueueTier,
+        AlreadyQueued,
+        NotQueued,
+        NotAllowlisted,
+        InvalidMerkleProof,
+        InvalidPasscode,
+        NoSeatOffer,
+        IncorrectPayment,
+        TokenTransferFailed,
+        UnsupportedCurrency,
+        TicketNotFound,
+        InvalidPeggedBuyIn,
+        InvalidPrice,
+        StalePrice,
+        SlippageExceeded,
+        InvalidFeeSplit,
+        InsufficientFees,
+        InvalidPlayerLimits,
+        DuplicateTeam,
+        DuplicatePlacement,
+        SelfApproval,
+        OracleCallFailed,
     }
 
     /// Contract result type
     pub type Result<T> = core::result::Result<T, Error>;
 
+    /// Identifier of a seat ticket
+    pub type TicketId = u32;
+
+    /// Number of players kept on the season leaderboard
+    pub const SEASON_LEADERBOARD_SIZE: usize = 100;
+
+    /// Maximum number of queue entries looked at when forming a game
+    const QUEUE_SCAN_LIMIT: u32 = 64;
+
+    /// Selector of `PriceOracle::latest_price`
+    const LATEST_PRICE_SELECTOR: [u8; 4] = ink::selector_bytes!("PriceOracle::latest_price");
+    /// Selector of the token's `transfer(to, value)` message
+    const TOKEN_TRANSFER_SELECTOR: [u8; 4] = ink::selector_bytes!("transfer");
+    /// Selector of the token's `transfer_from(from, to, value)` message
+    const TOKEN_TRANSFER_FROM_SELECTOR: [u8; 4] = ink::selector_bytes!("transfer_from");
+
+    /// Rating of players without any rated game
+    pub const DEFAULT_RATING: u32 = 1500;
+    /// Maximum rating change per game (Elo K-factor)
+    const RATING_K_FACTOR: i64 = 32;
+    /// Fixed-point scale of expected scores
+    const SCORE_SCALE: i64 = 10_000;
+    /// Step between entries of the expected score table, in rating points
+    const SCORE_TABLE_STEP: i64 = 50;
+    /// Expected score of the higher rated player for rating differences 0, 50, ..., 800
+    const EXPECTED_SCORE_TABLE: [i64; 17] = [
+        5000, 5715, 6401, 7034, 7597, 8083, 8490, 8823, 9091, 9302, 9468, 9595, 9693, 9768, 9825,
+        9868, 9901,
+    ];
+
+    /// Elo expected score (scaled by `SCORE_SCALE`) of a player rated `diff` above the opponent
+    ///
+    /// Uses linear interpolation over a fixed table so results are deterministic in `no_std`.
+    fn expected_score(diff: i64) -> i64 {
+        let max_diff = SCORE_TABLE_STEP * (EXPECTED_SCORE_TABLE.len() as i64 - 1);
+        let distance = diff.abs().min(max_diff);
+        let index = (distance / SCORE_TABLE_STEP) as usize;
+        let base = EXPECTED_SCORE_TABLE[index];
+        let next = EXPECTED_SCORE_TABLE[(index + 1).min(EXPECTED_SCORE_TABLE.len() - 1)];
+        let score = base + (next - base) * (distance % SCORE_TABLE_STEP) / SCORE_TABLE_STEP;
+        if diff >= 0 {
+            score
+        } else {
+            SCORE_SCALE - score
+        }
+    }
+
+    /// Keccak-256 hash of `input`
+    fn keccak256(input: &[u8]) -> [u8; 32] {
+        let mut output = [0u8; 32];
+        ink::env::hash_bytes::<ink::env::hash::Keccak256>(input, &mut output);
+        output
+    }
+
+    /// Convert a pegged buy-in with an oracle price `(price, updated_at)` read at `now`
+    fn pegged_amount(
+        peg: &PeggedBuyIn,
+        (price, updated_at): (u128, u64),
+        now: Timestamp,
+    ) -> Result<Balance> {
+        // A price from the future cannot be aged, treat it like a broken feed
+        if price == 0 || updated_at > now {
+            return Err(Error::InvalidPrice);
+        }
+        if now.saturating_sub(updated_at) > peg.max_price_age {
+            return Err(Error::StalePrice);
+        }
+        // A price too large to convert is as unusable as a zero one
+        peg.reference_amount
+            .checked_mul(price)
+            .ok_or(Error::InvalidPrice)
+    }
+
+    /// Whether `amount` is within `tolerance_bps` basis points of `quoted`
+    fn within_tolerance(amount: Balance, quoted: Balance, tolerance_bps: u16) -> bool {
+        let tolerance = quoted.saturating_mul(tolerance_bps as Balance) / 10_000;
+        amount.abs_diff(quoted) <= tolerance
+    }
+
+    /// Merkle parent of two nodes, hashed in sorted order so proofs need no direction bits
+    fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        let (first, second) = if a <= b { (a, b) } else { (b, a) };
+        let mut input = [0u8; 64];
+        input[..32].copy_from_slice(first);
+        input[32..].copy_from_slice(second);
+        keccak256(&input)
+    }
+
     /*
      * 🎯 TASK 6 COMPLETE: Enhanced Events & Game Server Integration
      *
@@ -154,10 +780,12 @@ mod agario_buyin {
             Ok(Self {
                 game_admin: Self::env().caller(),
                 admin_fee_percentage: admin_fee,
+                roles: Mapping::default(),
                 game_state: GameState::Inactive,
                 buy_in_amount: 0,
 
                 // Initialize timing fields
+                time_basis: TimeBasis::Timestamp,
                 registration_deadline: 0,
                 min_players: 0,
                 max_players: None,
@@ -165,8 +793,122 @@ mod agario_buyin {
                 // Initialize game duration fields
                 game_duration: None,
                 game_start_time: 0,
+                settlement_period: None,
+                settlement_deadline: 0,
+
+                // Initialize late registration fields
+                late_registration_period: None,
+                late_buy_in_amount: None,
+
+                // Initialize re-buy fields
+                rebuy_config: None,
+                rebuy_count: 0,
+
+                // Initialize cash-game fields
+                cash_game: false,
+
+                // Initialize bounty fields
+                bounty_percentage: 0,
+                bounty_pool: 0,
+
+                // Initialize alive-set fields
+                alive_count: 0,
+                elimination_count: 0,
+                elimination_log: Mapping::default(),
+
+                // Initialize team fields
+                team_config: None,
+                team_sizes: Mapping::default(),
+                team_members: Mapping::default(),
+
+                // Initialize tournament fields
+                tournament: None,
+                tournament_count: 0,
+                tournament_entrants: Mapping::default(),
+                tournament_stage_sizes: Mapping::default(),
+                tournament_refunds: Mapping::default(),
+
+                // Initialize sponsorship and guarantee fields
+                sponsors: Mapping::default(),
+                sponsor_count: 0,
+                sponsor_contributions: Mapping::default(),
+                sponsored_amount: 0,
+                treasury_balance: 0,
+                guaranteed_pool: None,
+                guarantee_reserve: 0,
+                overlay: 0,
+                total_overlay: 0,
+
+                // Initialize jackpot fields
+                jackpot: 0,
+                jackpot_config: JackpotConfig::default(),
+                jackpot_contributions: Mapping::default(),
+                jackpot_contribution_count: 0,
+
+                // Initialize season fields
+                season: None,
+                season_count: 0,
+                season_points: Mapping::default(),
+                season_leaderboard: Lazy::new(),
+                season_carryover: 0,
+
+                // Initialize roster and statistics fields
+                roster: Mapping::default(),
+                roster_len: 0,
+                player_stats: Mapping::default(),
+
+                // Initialize rating fields
+                ratings: Mapping::default(),
+                rating_band: None,
+
+                // Initialize entry gating fields
+                entry_gate: EntryGate::Open,
+                allowlist: Mapping::default(),
+
+                // Initialize matchmaking fields
+                queue_tiers: Mapping::default(),
+                queue_entries: Mapping::default(),
+                queue_bounds: Mapping::default(),
+                queue_sizes: Mapping::default(),
+                queued: Mapping::default(),
+
+                tickets: Mapping::default(),
+                ticket_balances: Mapping::default(),
+                ticket_approvals: Mapping::default(),
+                ticket_operators: Mapping::default(),
+                next_ticket_id: 0,
+                seat_offers: Mapping::default(),
+
+                // Initialize trophy and badge fields
+                trophy_places: 0,
+                trophies: Mapping::default(),
+                trophy_count: 0,
+                player_trophies: Mapping::default(),
+                player_trophy_counts: Mapping::default(),
+                badges: Mapping::default(),
+                biggest_pool: 0,
+
+                // Initialize referral fields
+                referral_percentage: 0,
+                referrers: Mapping::default(),
+                referral_counts: Mapping::default(),
+                referral_earnings: Mapping::default(),
+
+                claimable: Mapping::default(),
+                token_claimable: Mapping::default(),
+                currency: Currency::Native,
+                pegged_buy_in: None,
+                accrued_fees: Mapping::default(),
+                lifetime_fees: Mapping::default(),
+                fee_split: None,
+                round_fee_split: None,
+                entry_fee: 0,
+                keep_entry_fee_on_refund: false,
+                entry_fees: 0,
+                total_entry_fees: 0,
 
                 // Initialize player and prize fields
+                current_round: 0,
                 players: Mapping::default(),
                 player_count: 0,
                 prize_pool: 0,
@@ -218,985 +960,5996 @@ mod agario_buyin {
         /// Check if a player is registered
         #[ink(message)]
         pub fn is_player_registered(&self, player: H160) -> bool {
-            self.players.get(player).is_some()
+            self.get_player_info(player).is_some()
         }
 
-        /// Get contract admin
+        /// Get a player's record for the current round
         #[ink(message)]
-        pub fn get_admin(&self) -> H160 {
-            self.game_admin
+        pub fn get_player_info(&self, player: H160) -> Option<PlayerInfo> {
+            self.players
+                .get(player)
+                .filter(|info| info.round == self.current_round)
         }
 
-        /// Get buy-in amount
+        /// Get current round number (incremented on every start_game)
         #[ink(message)]
-        pub fn get_buy_in_amount(&self) -> Balance {
-            self.buy_in_amount
+        pub fn get_current_round(&self) -> u32 {
+            self.current_round
         }
 
-        /// Get time remaining for registration (if in AcceptingDeposits state)
+        /// Get contract admin
         #[ink(message)]
-        pub fn get_registration_time_remaining(&self) -> Timestamp {
-            if self.game_state != GameState::AcceptingDeposits {
-                return 0;
-            }
-
-            let now = self.env().block_timestamp();
-            self.registration_deadline.saturating_sub(now)
+        pub fn get_admin(&self) -> H160 {
+            self.game_admin
         }
 
-        /// Get time remaining for game (if in InProgress state)
+        /// Check if an account holds a role
         #[ink(message)]
-        pub fn get_game_time_remaining(&self) -> Option<Timestamp> {
-            if self.game_state != GameState::InProgress {
-                return None;
-            }
+        pub fn has_role(&self, role: Role, account: H160) -> bool {
+            self.roles.contains((role, account))
+        }
 
-            match self.game_duration {
-                Some(duration) => {
-                    let now = self.env().block_timestamp();
-                    let game_end_time = self.game_start_time.saturating_add(duration);
-                    if now >= game_end_time {
-                        Some(0)
-                    } else {
-                        Some(game_end_time.saturating_sub(now))
-                    }
-                }
-                None => None, // No time limit
+        /// Grant a role to an account (Admin only)
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: Role, account: H160) -> Result<()> {
+            if self.env().caller() != self.game_admin {
+                return Err(Error::NotAdmin);
             }
+            self.roles.insert((role, account), &());
+            Ok(())
         }
 
-        /// Start a new game with specified parameters (Admin only)
+        /// Revoke a role from an account (Admin only)
         #[ink(message)]
-        pub fn start_game(
-            &mut self,
-            buy_in: Balance,
-            registration_minutes: u32,
-            min_players: u32,
-            game_duration_minutes: Option<u32>,
-        ) -> Result<()> {
-            // Check admin access
+        pub fn revoke_role(&mut self, role: Role, account: H160) -> Result<()> {
             if self.env().caller() != self.game_admin {
                 return Err(Error::NotAdmin);
             }
+            self.roles.remove((role, account));
+            Ok(())
+        }
 
-            // Check current state
-            if self.game_state != GameState::Inactive {
-                return Err(Error::GameNotInCorrectState);
-            }
+        /// Get an account's claimable balance
+        #[ink(message)]
+        pub fn get_claimable(&self, account: H160) -> Balance {
+            self.claimable.get(account).unwrap_or(0)
+        }
 
-            // Validate parameters
-            if min_players < 2 {
-                return Err(Error::TooFewPlayers);
-            }
+        /// Get total outstanding bounties of the current game
+        #[ink(message)]
+        pub fn get_bounty_pool(&self) -> Balance {
+            self.bounty_pool
+        }
 
-            // Set up game parameters
-            let now = self.env().block_timestamp();
-            self.buy_in_amount = buy_in;
-            self.min_players = min_players;
-            self.registration_deadline = now.saturating_add(
-                (registration_minutes as u64)
-                    .saturating_mul(60)
-                    .saturating_mul(1000),
-            ); // Convert minutes to milliseconds
-
-            // Set game duration if specified
-            self.game_duration = game_duration_minutes
-                .map(|minutes| (minutes as u64).saturating_mul(60).saturating_mul(1000));
+        /// Get a player's current bounty (0 if not registered)
+        #[ink(message)]
+        pub fn get_player_bounty(&self, player: H160) -> Balance {
+            self.get_player_info(player)
+                .map(|info| info.bounty)
+                .unwrap_or(0)
+        }
 
-            // Reset player data
-            self.player_count = 0;
-            self.prize_pool = 0;
+        /// Get number of players still alive in the current game
+        #[ink(message)]
+        pub fn get_alive_count(&self) -> u32 {
+            self.alive_count
+        }
 
-            // Change state to accepting deposits
-            self.game_state = GameState::AcceptingDeposits;
+        /// Check if a registered player has not been eliminated
+        #[ink(message)]
+        pub fn is_player_alive(&self, player: H160) -> bool {
+            self.get_player_info(player)
+                .map(|info| info.elimination_order.is_none())
+                .unwrap_or(false)
+        }
 
-            // Emit GameStarted event (commented for MVP due to ink! v6 compatibility)
-            // self.env().emit_event(GameStarted {
-            //     buy_in: self.buy_in_amount,
-            //     registration_deadline: self.registration_deadline,
-            //     min_players: self.min_players,
-            //     game_duration: self.game_duration,
-            // });
+        /// Get a player's position in the elimination order (1 = first out)
+        #[ink(message)]
+        pub fn get_elimination_order(&self, player: H160) -> Option<u32> {
+            self.get_player_info(player)
+                .and_then(|info| info.elimination_order)
+        }
 
-            Ok(())
+        /// Get the player eliminated at a given position of the current round
+        #[ink(message)]
+        pub fn get_eliminated_player(&self, order: u32) -> Option<H160> {
+            self.elimination_log.get((self.current_round, order))
         }
 
-        /// Allow players to deposit and join the game
-        #[ink(message, payable)]
-        pub fn deposit(&mut self) -> Result<()> {
-            // Check game state
-            if self.game_state != GameState::AcceptingDeposits {
-                return Err(Error::GameNotInCorrectState);
-            }
+        /// Get team configuration of the current game (None = individual game)
+        #[ink(message)]
+        pub fn get_team_config(&self) -> Option<TeamConfig> {
+            self.team_config
+        }
 
-            // Check registration deadline
-            let now = self.env().block_timestamp();
-            if now >= self.registration_deadline {
-                return Err(Error::RegistrationClosed);
-            }
+        /// Get number of players registered to a team in the current round
+        #[ink(message)]
+        pub fn get_team_size(&self, team: u32) -> u32 {
+            self.team_sizes.get((self.current_round, team)).unwrap_or(0)
+        }
 
-            let caller = self.env().caller();
-            let deposit_amount = self.env().transferred_value();
+        /// Get current members of a team (players who left the game are skipped)
+        #[ink(message)]
+        pub fn get_team_members(&self, team: u32) -> Vec<H160> {
+            (0..self.get_team_size(team))
+                .filter_map(|index| self.team_members.get((self.current_round, team, index)))
+                .filter(|member| self.is_player_registered(*member))
+                .collect()
+        }
 
-            // Check correct deposit amount
-            if deposit_amount != self.buy_in_amount.into() {
-                return Err(Error::IncorrectBuyInAmount);
-            }
+        /// Get the running tournament (None if no tournament)
+        #[ink(message)]
+        pub fn get_tournament(&self) -> Option<Tournament> {
+            self.tournament.clone()
+        }
 
-            // Check if player already deposited
-            if self.is_player_registered(caller) {
-                return Err(Error::PlayerAlreadyDeposited);
+        /// Get number of entrants in a stage of the running tournament
+        #[ink(message)]
+        pub fn get_tournament_stage_size(&self, stage: u32) -> u32 {
+            match &self.tournament {
+                Some(tournament) => self
+                    .tournament_stage_sizes
+                    .get((tournament.id, stage))
+                    .unwrap_or(0),
+                None => 0,
             }
+        }
 
-            // Check if game is full
-            if let Some(max_players) = self.max_players {
-                if self.player_count >= max_players {
-                    return Err(Error::GameFull);
-                }
-            }
+        /// Get entrants of a stage of the running tournament
+        #[ink(message)]
+        pub fn get_tournament_entrants(&self, stage: u32) -> Vec<H160> {
+            let id = match &self.tournament {
+                Some(tournament) => tournament.id,
+                None => return Vec::new(),
+            };
+            (0..self.get_tournament_stage_size(stage))
+                .filter_map(|index| self.tournament_entrants.get((id, stage, index)))
+                .collect()
+        }
 
-            // Add player
-            self.players.insert(caller, &());
-            self.player_count = self.player_count.saturating_add(1);
-            // Convert U256 to Balance (u128) safely
-            let deposit_as_balance: Balance = deposit_amount.try_into().unwrap_or(0);
-            self.prize_pool = self.prize_pool.saturating_add(deposit_as_balance);
+        /// Get a sponsor's contribution to a round
+        #[ink(message)]
+        pub fn get_sponsor_contribution(&self, round: u32, sponsor: H160) -> Balance {
+            self.sponsor_contributions
+                .get((round, sponsor))
+                .unwrap_or(0)
+        }
 
-            // Emit PlayerJoined event (commented for MVP due to ink! v6 compatibility)
-            // self.env().emit_event(PlayerJoined {
-            //     player: caller,
-            //     player_count: self.player_count,
-            //     prize_pool: self.prize_pool,
-            // });
+        /// Get total sponsor funds in the current game
+        #[ink(message)]
+        pub fn get_sponsored_amount(&self) -> Balance {
+            self.sponsored_amount
+        }
 
-            // Try to begin game if conditions are met
-            self.try_begin_game()?;
+        /// Get treasury balance available for guarantees
+        #[ink(message)]
+        pub fn get_treasury_balance(&self) -> Balance {
+            self.treasury_balance
+        }
 
-            Ok(())
+        /// Get guaranteed prize pool of the current game
+        #[ink(message)]
+        pub fn get_guaranteed_pool(&self) -> Option<Balance> {
+            self.guaranteed_pool
         }
 
-        /// Try to begin the game if conditions are met
+        /// Get treasury overlay of the current game
         #[ink(message)]
-        pub fn try_begin_game(&mut self) -> Result<()> {
-            // Only work if in AcceptingDeposits state
-            if self.game_state != GameState::AcceptingDeposits {
-                return Ok(()); // Not an error, just nothing to do
-            }
+        pub fn get_overlay(&self) -> Balance {
+            self.overlay
+        }
 
-            let now = self.env().block_timestamp();
+        /// Get lifetime treasury overlay cost
+        #[ink(message)]
+        pub fn get_total_overlay(&self) -> Balance {
+            self.total_overlay
+        }
 
-            // Check if registration deadline has passed
-            if now >= self.registration_deadline {
-                if self.player_count >= self.min_players {
-                    // Start the game
-                    self.game_state = GameState::InProgress;
-                    self.game_start_time = now;
-
-                    // Emit GameBegan event (commented for MVP due to ink! v6 compatibility)
-                    // self.env().emit_event(GameBegan {
-                    //     player_count: self.player_count,
-                    //     game_start_time: self.game_start_time,
-                    // });
-                } else {
-                    // Not enough players, refund everyone
-                    self.refund_all_players()?;
-                }
+        /// Get current jackpot balance
+        #[ink(message)]
+        pub fn get_jackpot(&self) -> Balance {
+            self.jackpot
+        }
+
+        /// Get jackpot configuration
+        #[ink(message)]
+        pub fn get_jackpot_config(&self) -> JackpotConfig {
+            self.jackpot_config
+        }
+
+        /// Get number of jackpot contributions so far
+        #[ink(message)]
+        pub fn get_jackpot_contribution_count(&self) -> u32 {
+            self.jackpot_contribution_count
+        }
+
+        /// Get jackpot contributions starting at `start`, at most `limit` entries
+        #[ink(message)]
+        pub fn get_jackpot_contributions(
+            &self,
+            start: u32,
+            limit: u32,
+        ) -> Vec<JackpotContribution> {
+            (start
+                ..start
+                    .saturating_add(limit)
+                    .min(self.jackpot_contribution_count))
+                .filter_map(|index| self.jackpot_contributions.get(index))
+                .collect()
+        }
+
+        /// Get the running season (None if no season)
+        #[ink(message)]
+        pub fn get_season(&self) -> Option<Season> {
+            self.season.clone()
+        }
+
+        /// Get a player's points in a season
+        #[ink(message)]
+        pub fn get_season_points(&self, season_id: u32, player: H160) -> u32 {
+            self.season_points.get((season_id, player)).unwrap_or(0)
+        }
+
+        /// Get the running season's leaderboard starting at rank `start`, at most `limit` entries
+        ///
+        /// Only the top `SEASON_LEADERBOARD_SIZE` players are ranked.
+        #[ink(message)]
+        pub fn get_season_leaderboard(&self, start: u32, limit: u32) -> Vec<(H160, u32)> {
+            if self.season.is_none() {
+                return Vec::new();
             }
+            self.season_leaderboard
+                .get_or_default()
+                .into_iter()
+                .skip(start as usize)
+                .take(limit as usize)
+                .collect()
+        }
 
-            Ok(())
+        /// Get the season pool left unpaid, added to the next season's pool
+        #[ink(message)]
+        pub fn get_season_carryover(&self) -> Balance {
+            self.season_carryover
         }
 
-        /// Check game conditions and handle automatic state transitions
+        /// Get a player's skill rating
         #[ink(message)]
-        pub fn check_game_conditions(&mut self) -> Result<()> {
-            let now = self.env().block_timestamp();
+        pub fn get_rating(&self, player: H160) -> u32 {
+            self.ratings.get(player).unwrap_or(DEFAULT_RATING)
+        }
 
-            match self.game_state {
-                GameState::AcceptingDeposits => {
-                    // Check if registration deadline passed
-                    if now >= self.registration_deadline {
-                        if self.player_count >= self.min_players {
-                            self.game_state = GameState::InProgress;
-                            self.game_start_time = now;
-                            // Emit GameBegan event (commented for MVP due to ink! v6 compatibility)
-                            // self.env().emit_event(GameBegan {
-                            //     player_count: self.player_count,
-                            //     game_start_time: self.game_start_time,
-                            // });
-                        } else {
-                            // Refund all players and reset
-                            self.refund_all_players()?;
-                        }
-                    }
-                }
-                GameState::InProgress => {
-                    // Check if game duration exceeded
-                    if let Some(duration) = self.game_duration {
-                        if now >= self.game_start_time + duration {
-                            self.game_state = GameState::WaitingForResults;
-                            // Emit GameTimeExpired event (commented for MVP due to ink! v6 compatibility)
-                            // self.env().emit_event(GameTimeExpired {
-                            //     game_end_time: now,
-                            // });
-                        }
-                    }
-                }
-                _ => {}
+        /// Get rating band of the current game (None = open to all ratings)
+        #[ink(message)]
+        pub fn get_rating_band(&self) -> Option<(u32, u32)> {
+            self.rating_band
+        }
+
+        /// Get entry gating of the current game
+        #[ink(message)]
+        pub fn get_entry_gate(&self) -> EntryGate {
+            self.entry_gate
+        }
+
+        /// Check if an address is on the admin-managed allowlist of a round
+        #[ink(message)]
+        pub fn is_allowlisted(&self, round: u32, player: H160) -> bool {
+            self.allowlist.contains((round, player))
+        }
+
+        /// Get the referrer recorded for a player
+        #[ink(message)]
+        pub fn get_referrer(&self, player: H160) -> Option<H160> {
+            self.referrers.get(player)
+        }
+
+        /// Get number of players a referrer brought in
+        #[ink(message)]
+        pub fn get_referral_count(&self, referrer: H160) -> u32 {
+            self.referral_counts.get(referrer).unwrap_or(0)
+        }
+
+        /// Get lifetime referral earnings of a referrer
+        #[ink(message)]
+        pub fn get_referral_earnings(&self, referrer: H160) -> Balance {
+            self.referral_earnings.get(referrer).unwrap_or(0)
+        }
+
+        /// Get referrer share of the admin fee in percent
+        #[ink(message)]
+        pub fn get_referral_percentage(&self) -> u8 {
+            self.referral_percentage
+        }
+
+        /// Get currency of the current game
+        #[ink(message)]
+        pub fn get_currency(&self) -> Currency {
+            self.currency
+        }
+
+        /// Get an account's claimable balance of a token
+        #[ink(message)]
+        pub fn get_token_claimable(&self, token: H160, account: H160) -> Balance {
+            self.token_claimable.get((token, account)).unwrap_or(0)
+        }
+
+        /// Get a ticket's owner, None if it does not exist or was burned
+        #[ink(message)]
+        pub fn ticket_owner_of(&self, ticket: TicketId) -> Option<H160> {
+            self.tickets.get(ticket).map(|info| info.owner)
+        }
+
+        /// Get number of tickets an account holds
+        #[ink(message)]
+        pub fn ticket_balance_of(&self, owner: H160) -> u32 {
+            self.ticket_balances.get(owner).unwrap_or(0)
+        }
+
+        /// Get a ticket's details for display
+        #[ink(message)]
+        pub fn get_ticket(&self, ticket: TicketId) -> Option<Ticket> {
+            self.tickets.get(ticket)
+        }
+
+        /// Get the account approved to transfer a ticket
+        #[ink(message)]
+        pub fn get_ticket_approved(&self, ticket: TicketId) -> Option<H160> {
+            self.ticket_approvals.get(ticket)
+        }
+
+        /// Check if an operator may transfer all of an owner's tickets
+        #[ink(message)]
+        pub fn is_ticket_approved_for_all(&self, owner: H160, operator: H160) -> bool {
+            self.ticket_operators.contains((owner, operator))
+        }
+
+        /// Get a trophy by id
+        #[ink(message)]
+        pub fn get_trophy(&self, id: u32) -> Option<Trophy> {
+            self.trophies.get(id)
+        }
+
+        /// Get number of trophies a player holds
+        #[ink(message)]
+        pub fn get_trophy_count_of(&self, player: H160) -> u32 {
+            self.player_trophy_counts.get(player).unwrap_or(0)
+        }
+
+        /// Get a player's trophies, oldest first
+        #[ink(message)]
+        pub fn get_player_trophies(&self, player: H160) -> Vec<Trophy> {
+            (0..self.get_trophy_count_of(player))
+                .filter_map(|index| self.player_trophies.get((player, index)))
+                .filter_map(|id| self.trophies.get(id))
+                .collect()
+        }
+
+        /// Get the round a badge was awarded in, None if the player does not have it
+        #[ink(message)]
+        pub fn get_badge(&self, player: H160, badge: Badge) -> Option<u32> {
+            self.badges.get((player, badge))
+        }
+
+        /// Get the reference currency pricing of the current game's buy-in
+        #[ink(message)]
+        pub fn get_pegged_buy_in(&self) -> Option<PeggedBuyIn> {
+            self.pegged_buy_in
+        }
+
+        /// Get fees accrued and not yet withdrawn
+        #[ink(message)]
+        pub fn get_accrued_fees(&self, currency: Currency) -> Balance {
+            self.accrued_fees.get(currency).unwrap_or(0)
+        }
+
+        /// Get all fees collected over the contract's lifetime
+        #[ink(message)]
+        pub fn get_lifetime_fees(&self, currency: Currency) -> Balance {
+            self.lifetime_fees.get(currency).unwrap_or(0)
+        }
+
+        /// Get the fee split that applies from the next round on
+        #[ink(message)]
+        pub fn get_fee_split(&self) -> Option<FeeSplit> {
+            self.fee_split
+        }
+
+        /// Get the fee split of the current round
+        #[ink(message)]
+        pub fn get_round_fee_split(&self) -> Option<FeeSplit> {
+            self.round_fee_split
+        }
+
+        /// Get entry fees collected in the current round
+        #[ink(message)]
+        pub fn get_entry_fees(&self) -> Balance {
+            self.entry_fees
+        }
+
+        /// Get lifetime entry fees collected
+        #[ink(message)]
+        pub fn get_total_entry_fees(&self) -> Balance {
+            self.total_entry_fees
+        }
+
+        /// Get the open offer for a holder's seat as (buyer, price)
+        #[ink(message)]
+        pub fn get_seat_offer(&self, holder: H160) -> Option<(H160, Balance)> {
+            self.seat_offers.get((self.current_round, holder))
+        }
+
+        /// Get matchmaking settings of a stake tier
+        #[ink(message)]
+        pub fn get_queue_tier(&self, stake: Balance) -> Option<QueueTier> {
+            self.queue_tiers.get(stake)
+        }
+
+        /// Get number of players waiting in a stake tier's queue
+        #[ink(message)]
+        pub fn get_queue_size(&self, stake: Balance) -> u32 {
+            self.queue_sizes.get(stake).unwrap_or(0)
+        }
+
+        /// Get the stake of the queue a player is waiting in
+        #[ink(message)]
+        pub fn get_queued_stake(&self, player: H160) -> Option<Balance> {
+            self.queued.get(player).map(|(stake, _)| stake)
+        }
+
+        /// Get lifetime statistics of a wallet
+        #[ink(message)]
+        pub fn get_player_stats(&self, player: H160) -> PlayerStats {
+            self.player_stats.get(player).unwrap_or_default()
+        }
+
+        /// Check if the current game is a cash game
+        #[ink(message)]
+        pub fn is_cash_game(&self) -> bool {
+            self.cash_game
+        }
+
+        /// Get buy-in amount
+        #[ink(message)]
+        pub fn get_buy_in_amount(&self) -> Balance {
+            self.buy_in_amount
+        }
+
+        /// Get the time basis used for all deadlines of the current game
+        #[ink(message)]
+        pub fn get_time_basis(&self) -> TimeBasis {
+            self.time_basis
+        }
+
+        /// Get settlement deadline (0 if not set)
+        #[ink(message)]
+        pub fn get_settlement_deadline(&self) -> Timestamp {
+            self.settlement_deadline
+        }
+
+        /// Get time remaining for registration (if in AcceptingDeposits state)
+        #[ink(message)]
+        pub fn get_registration_time_remaining(&self) -> Timestamp {
+            if self.game_state != GameState::AcceptingDeposits {
+                return 0;
             }
-            Ok(())
+
+            let now = self.now();
+            self.registration_deadline.saturating_sub(now)
         }
 
-        /// Report that the game has ended (called by game server or admin)
+        /// Get time remaining for game (if in InProgress state)
         #[ink(message)]
-        pub fn report_game_end(&mut self, reason: GameEndReason) -> Result<()> {
-            // Check state
+        pub fn get_game_time_remaining(&self) -> Option<Timestamp> {
             if self.game_state != GameState::InProgress {
-                return Err(Error::GameNotInCorrectState);
+                return None;
             }
 
-            // Only admin can force end, game server can report natural end
-            match reason {
-                GameEndReason::AdminForced => {
-                    if self.env().caller() != self.game_admin {
-                        return Err(Error::NotAdmin);
+            match self.game_duration {
+                Some(duration) => {
+                    let now = self.now();
+                    let game_end_time = self.game_start_time.saturating_add(duration);
+                    if now >= game_end_time {
+                        Some(0)
+                    } else {
+                        Some(game_end_time.saturating_sub(now))
                     }
                 }
-                GameEndReason::TimeLimit | GameEndReason::LastPlayerStanding => {
-                    // Game server can report these, for MVP we'll allow any caller
-                    // In production, you'd want to verify the caller is the authorized game server
+                None => None, // No time limit
+            }
+        }
+
+        /// Get time remaining for late registration (if in InProgress state with a late window)
+        #[ink(message)]
+        pub fn get_late_registration_time_remaining(&self) -> Option<Timestamp> {
+            if self.game_state != GameState::InProgress {
+                return None;
+            }
+
+            match self.late_registration_period {
+                Some(period) => {
+                    let late_deadline = self.game_start_time.saturating_add(period);
+                    Some(late_deadline.saturating_sub(self.now()))
                 }
+                None => None, // No late registration
             }
+        }
 
-            // Move to waiting for results
-            self.game_state = GameState::WaitingForResults;
+        /// Get buy-in amount for late entrants
+        #[ink(message)]
+        pub fn get_late_buy_in_amount(&self) -> Balance {
+            self.late_buy_in_amount.unwrap_or(self.buy_in_amount)
+        }
 
-            // Emit GameTimeExpired event for time-based endings
-            if reason == GameEndReason::TimeLimit {
-                // self.env().emit_event(GameTimeExpired {
-                //     game_end_time: self.env().block_timestamp(),
-                // });
+        /// Get a player's number of paid entries in the current round (0 if not registered)
+        #[ink(message)]
+        pub fn get_player_entries(&self, player: H160) -> u32 {
+            self.get_player_info(player)
+                .map(|info| info.entries)
+                .unwrap_or(0)
+        }
+
+        /// Get re-buy configuration of the current game
+        #[ink(message)]
+        pub fn get_rebuy_config(&self) -> Option<RebuyConfig> {
+            self.rebuy_config
+        }
+
+        /// Get number of re-buys made in the current game
+        #[ink(message)]
+        pub fn get_rebuy_count(&self) -> u32 {
+            self.rebuy_count
+        }
+
+        /// Get time remaining for winner submission (if in WaitingForResults state)
+        #[ink(message)]
+        pub fn get_settlement_time_remaining(&self) -> Option<Timestamp> {
+            if self.game_state != GameState::WaitingForResults {
+                return None;
             }
 
-            Ok(())
+            match self.settlement_period {
+                Some(_) => Some(self.settlement_deadline.saturating_sub(self.now())),
+                None => None, // No settlement deadline
+            }
         }
 
-        /// Submit winners and distribute prizes (Admin only)
+        /// Start a new game with specified parameters (Admin only)
         #[ink(message)]
-        pub fn submit_winners(
+        pub fn start_game(
             &mut self,
-            winners: Vec<H160>,
-            percentages: Vec<u8>,
-            _reason: GameEndReason,
+            buy_in: Balance,
+            registration_minutes: u32,
+            min_players: u32,
+            game_duration_minutes: Option<u32>,
         ) -> Result<()> {
+            self.start_game_with_config(GameConfig {
+                buy_in,
+                min_players,
+                registration_period: registration_minutes,
+                game_duration: game_duration_minutes,
+                ..GameConfig::default()
+            })
+        }
+
+        /// Start a new game from a full configuration (Admin only)
+        #[ink(message)]
+        pub fn start_game_with_config(&mut self, config: GameConfig) -> Result<()> {
             // Check admin access
             if self.env().caller() != self.game_admin {
                 return Err(Error::NotAdmin);
             }
 
-            // Check state
-            if self.game_state != GameState::WaitingForResults {
+            self.setup_game(config)
+        }
+
+        /// Internal function validating a configuration and opening a new round
+        fn setup_game(&mut self, config: GameConfig) -> Result<()> {
+            // Check current state
+            if self.game_state != GameState::Inactive {
                 return Err(Error::GameNotInCorrectState);
             }
 
-            // Validate input
-            if winners.is_empty() {
-                return Err(Error::NoWinners);
+            // Validate parameters
+            if config.min_players < 2 {
+                return Err(Error::TooFewPlayers);
             }
-
-            if winners.len() != percentages.len() {
-                return Err(Error::MismatchedData);
+            if let Some(max_players) = config.max_players {
+                if max_players < config.min_players {
+                    return Err(Error::InvalidPlayerLimits);
+                }
             }
-
-            let total_percentage: u8 = percentages.iter().sum();
-            if total_percentage > 100 {
+            if config.bounty_percentage > 100 {
                 return Err(Error::InvalidPercentages);
             }
+            // The treasury holds the native currency only
+            if config.currency != Currency::Native && config.guaranteed_pool.is_some() {
+                return Err(Error::UnsupportedCurrency);
+            }
+            if let Some((min_rating, max_rating)) = config.rating_band {
+                if min_rating > max_rating {
+                    return Err(Error::InvalidRatingBand);
+                }
+            }
+            if let Some(peg) = config.pegged_buy_in {
+                // Oracles quote the native currency
+                if config.currency != Currency::Native {
+                    return Err(Error::UnsupportedCurrency);
+                }
+                if peg.reference_amount == 0 || peg.slippage_bps.is_some_and(|bps| bps > 10_000) {
+                    return Err(Error::InvalidPeggedBuyIn);
+                }
+            }
+            if let Some(teams) = config.teams {
+                if teams.team_count < 2
+                    || teams.min_team_size == 0
+                    || teams.max_team_size < teams.min_team_size
+                {
+                    return Err(Error::InvalidTeamConfig);
+                }
+            }
 
-            // Calculate admin fee
-            let admin_cut = self
-                .prize_pool
-                .saturating_mul(self.admin_fee_percentage as Balance)
-                .checked_div(100)
-                .unwrap_or(0);
-            let winner_pool = self.prize_pool.saturating_sub(admin_cut);
-
-            // Store total for event
-            let _total_distributed = self.prize_pool;
-
-            // Distribute prizes to winners
-            for (winner, percentage) in winners.iter().zip(percentages.iter()) {
-                let prize = winner_pool
-                    .saturating_mul(*percentage as Balance)
-                    .checked_div(100)
-                    .unwrap_or(0);
-                if prize > 0 {
-                    self.env()
-                        .transfer(*winner, prize.into())
-                        .map_err(|_| Error::TransferFailed)?;
+            // Reserve the guarantee from the treasury
+            let guarantee = config.guaranteed_pool.unwrap_or(0);
+            if guarantee > self.treasury_balance {
+                return Err(Error::InsufficientTreasury);
+            }
+            self.treasury_balance = self.treasury_balance.saturating_sub(guarantee);
+            self.guarantee_reserve = guarantee;
+            self.guaranteed_pool = config.guaranteed_pool;
+
+            // Set up game parameters
+            let basis = config.time_basis;
+            self.time_basis = basis;
+            let now = self.now();
+            self.buy_in_amount = match config.pegged_buy_in {
+                Some(peg) => self.quote_pegged_buy_in(&peg)?,
+                None => config.buy_in,
+            };
+            self.pegged_buy_in = config.pegged_buy_in;
+            self.entry_fee = config.entry_fee;
+            self.keep_entry_fee_on_refund = config.keep_entry_fee_on_refund;
+            self.round_fee_split = self.fee_split;
+            self.entry_fees = 0;
+            self.min_players = config.min_players;
+            self.max_players = config.max_players;
+            self.registration_deadline =
+                now.saturating_add(basis.period_to_units(config.registration_period));
+
+            // Set game duration and settlement window if specified
+            self.game_duration = config
+                .game_duration
+                .map(|period| basis.period_to_units(period));
+            self.settlement_period = config
+                .settlement_period
+                .map(|period| basis.period_to_units(period));
+
+            // Set late registration window if specified
+            self.late_registration_period = config
+                .late_registration_period
+                .map(|period| basis.period_to_units(period));
+            self.late_buy_in_amount = config.late_buy_in;
+
+            // Set re-buy limits if specified
+            self.rebuy_config = config.rebuy;
+            self.rebuy_count = 0;
+            self.cash_game = config.cash_game;
+            self.bounty_percentage = config.bounty_percentage;
+            self.bounty_pool = 0;
+            self.team_config = config.teams;
+            self.rating_band = config.rating_band;
+            self.entry_gate = config.entry_gate;
+            self.currency = config.currency;
+
+            // Reset player data
+            self.current_round = self.current_round.saturating_add(1);
+            self.roster_len = 0;
+            self.player_count = 0;
+            self.prize_pool = 0;
+
+            // Change state to accepting deposits
+            self.game_state = GameState::AcceptingDeposits;
+
+            // Emit GameStarted event (commented for MVP due to ink! v6 compatibility)
+            // self.env().emit_event(GameStarted {
+            //     buy_in: self.buy_in_amount,
+            //     registration_deadline: self.registration_deadline,
+            //     min_players: self.min_players,
+            //     game_duration: self.game_duration,
+            // });
+
+            Ok(())
+        }
+
+        /// Allow players to deposit and join the game
+        ///
+        /// During `InProgress` this is only possible within the late registration window.
+        #[ink(message, payable)]
+        pub fn deposit(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            self.register_player(caller, None, None)
+        }
+
+        /// Pay the buy-in for another player, who takes the seat and any winnings
+        ///
+        /// The caller is recorded as payer and gets the buy-in back if the game is refunded.
+        #[ink(message, payable)]
+        pub fn deposit_for(&mut self, player: H160) -> Result<()> {
+            self.register_player(player, None, None)
+        }
+
+        /// Deposit and join a team (team games only)
+        #[ink(message, payable)]
+        pub fn deposit_to_team(&mut self, team: u32) -> Result<()> {
+            let caller = self.env().caller();
+            self.register_player(caller, Some(team), None)
+        }
+
+        /// Deposit into a Merkle or passcode gated game
+        #[ink(message, payable)]
+        pub fn deposit_with_credential(
+            &mut self,
+            team: Option<u32>,
+            credential: EntryCredential,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            self.register_player(caller, team, Some(credential))
+        }
+
+        /// Deposit and record `referrer` as the caller's referrer (kept from the first referral)
+        #[ink(message, payable)]
+        pub fn deposit_with_referrer(&mut self, referrer: H160) -> Result<()> {
+            let caller = self.env().caller();
+            self.register_player(caller, None, None)?;
+
+            if referrer != caller && !self.referrers.contains(caller) {
+                self.referrers.insert(caller, &referrer);
+                self.referral_counts.insert(
+                    referrer,
+                    &self.get_referral_count(referrer).saturating_add(1),
+                );
+            }
+
+            // The new seat earns the caller's referrer
+            if let Some(mut info) = self.get_player_info(caller) {
+                info.referrer = self.referrers.get(caller);
+                self.players.insert(caller, &info);
+            }
+
+            Ok(())
+        }
+
+        /// Set the referrer share of a referred player's slice of the admin fee (Admin only)
+        #[ink(message)]
+        pub fn set_referral_percentage(&mut self, percentage: u8) -> Result<()> {
+            // Check admin access
+            if self.env().caller() != self.game_admin {
+                return Err(Error::NotAdmin);
+            }
+            if percentage > 100 {
+                return Err(Error::InvalidPercentages);
+            }
+            self.referral_percentage = percentage;
+            Ok(())
+        }
+
+        /// Give the caller's seat to another address before the game begins
+        #[ink(message)]
+        pub fn transfer_seat(&mut self, to: H160) -> Result<()> {
+            let caller = self.env().caller();
+            self.move_seat(caller, to, None)
+        }
+
+        /// Offer the caller's seat to `to` for `price`, paid through `accept_seat`
+        #[ink(message)]
+        pub fn offer_seat(&mut self, to: H160, price: Balance) -> Result<()> {
+            if self.game_state != GameState::AcceptingDeposits {
+                return Err(Error::GameNotInCorrectState);
+            }
+            let caller = self.env().caller();
+            let info = self
+                .get_player_info(caller)
+                .ok_or(Error::PlayerNotRegistered)?;
+            if info.payer.is_some() {
+                return Err(Error::NotAuthorized);
+            }
+
+            self.seat_offers
+                .insert((self.current_round, caller), &(to, price));
+            Ok(())
+        }
+
+        /// Take a seat offered to the caller, paying its holder the asking price in the same call
+        #[ink(message, payable)]
+        pub fn accept_seat(
+            &mut self,
+            from: H160,
+            credential: Option<EntryCredential>,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            let (_, price) = self
+                .get_seat_offer(from)
+                .filter(|(buyer, _)| *buyer == caller)
+                .ok_or(Error::NoSeatOffer)?;
+            if self.env().transferred_value() != price.into() {
+                return Err(Error::IncorrectPayment);
+            }
+
+            self.move_seat(from, caller, credential.as_ref())?;
+
+            // The seller claims the sale price like any other payout
+            self.credit(from, price);
+
+            Ok(())
+        }
+
+        /// Transfer a seat ticket, moving the seat with it (only before the game begins)
+        ///
+        /// The caller must own the ticket or be approved for it.
+        #[ink(message)]
+        pub fn transfer_ticket_from(
+            &mut self,
+            from: H160,
+            to: H160,
+            ticket: TicketId,
+        ) -> Result<()> {
+            let info = self.tickets.get(ticket).ok_or(Error::TicketNotFound)?;
+            let caller = self.env().caller();
+            if info.owner != from
+                || (caller != from
+                    && self.ticket_approvals.get(ticket) != Some(caller)
+                    && !self.is_ticket_approved_for_all(from, caller))
+            {
+                return Err(Error::NotAuthorized);
+            }
+            if info.round != self.current_round {
+                return Err(Error::InvalidRound);
+            }
+
+            self.move_seat(from, to, None)
+        }
+
+        /// Approve an account to transfer one of the caller's tickets
+        #[ink(message)]
+        pub fn approve_ticket(&mut self, to: H160, ticket: TicketId) -> Result<()> {
+            let info = self.tickets.get(ticket).ok_or(Error::TicketNotFound)?;
+            if info.owner != self.env().caller() {
+                return Err(Error::NotAuthorized);
+            }
+            self.ticket_approvals.insert(ticket, &to);
+            Ok(())
+        }
+
+        /// Approve or revoke an operator for all of the caller's tickets
+        #[ink(message)]
+        pub fn set_ticket_approval_for_all(
+            &mut self,
+            operator: H160,
+            approved: bool,
+        ) -> Result<()> {
+            let owner = self.env().caller();
+            if operator == owner {
+                return Err(Error::SelfApproval);
+            }
+            if approved {
+                self.ticket_operators.insert((owner, operator), &());
+            } else {
+                self.ticket_operators.remove((owner, operator));
+            }
+            Ok(())
+        }
+
+        /// Withdraw accrued native fees (Treasurer only)
+        #[ink(message)]
+        pub fn withdraw_fees(&mut self, to: H160, amount: Balance) -> Result<()> {
+            self.ensure_role(Role::Treasurer)?;
+            self.take_accrued_fees(Currency::Native, amount)?;
+
+            self.env()
+                .transfer(to, amount.into())
+                .map_err(|_| Error::TransferFailed)?;
+
+            Ok(())
+        }
+
+        /// Withdraw accrued fees of a token (Treasurer only)
+        #[ink(message)]
+        pub fn withdraw_token_fees(
+            &mut self,
+            token: H160,
+            to: H160,
+            amount: Balance,
+        ) -> Result<()> {
+            self.ensure_role(Role::Treasurer)?;
+            self.take_accrued_fees(Currency::Token(token), amount)?;
+
+            self.token_transfer(token, to, amount)
+        }
+
+        /// Set or clear the fee split used from the next round on (Admin only)
+        #[ink(message)]
+        pub fn set_fee_split(&mut self, split: Option<FeeSplit>) -> Result<()> {
+            // Check admin access
+            if self.env().caller() != self.game_admin {
+                return Err(Error::NotAdmin);
+            }
+
+            if let Some(split) = split {
+                let total_bps = split.treasury_bps as u32
+                    + split.host_bps as u32
+                    + split.dev_fund_bps as u32
+                    + split.charity_bps as u32;
+                if total_bps != 10_000 || (split.charity.is_none() && split.charity_bps > 0) {
+                    return Err(Error::InvalidFeeSplit);
                 }
             }
+            self.fee_split = split;
+            Ok(())
+        }
 
-            // Transfer admin fee
-            if admin_cut > 0 {
-                self.env()
-                    .transfer(self.game_admin, admin_cut.into())
-                    .map_err(|_| Error::TransferFailed)?;
+        /// Set how many top placements receive a trophy, 0 = none (Admin only)
+        #[ink(message)]
+        pub fn set_trophy_places(&mut self, places: u32) -> Result<()> {
+            // Check admin access
+            if self.env().caller() != self.game_admin {
+                return Err(Error::NotAdmin);
             }
+            self.trophy_places = places;
+            Ok(())
+        }
 
-            // Emit GameEnded event (commented for MVP due to ink! v6 compatibility)
-            // self.env().emit_event(GameEnded {
-            //     total_distributed: _total_distributed,
-            //     winners: winners.clone(),
-            //     percentages: percentages.clone(),
-            //     admin_fee: admin_cut,
-            //     reason: _reason,
+        /// Add or remove addresses on the allowlist of a round (Admin only)
+        ///
+        /// `round` is the current round or a later one; the next game started is round
+        /// `get_current_round() + 1`.
+        #[ink(message)]
+        pub fn set_allowlisted(
+            &mut self,
+            round: u32,
+            players: Vec<H160>,
+            allowed: bool,
+        ) -> Result<()> {
+            // Check admin access
+            if self.env().caller() != self.game_admin {
+                return Err(Error::NotAdmin);
+            }
+            if round < self.current_round {
+                return Err(Error::InvalidRound);
+            }
+
+            for player in players {
+                if allowed {
+                    self.allowlist.insert((round, player), &());
+                } else {
+                    self.allowlist.remove((round, player));
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Internal function registering `player` with the buy-in transferred by the caller
+        fn register_player(
+            &mut self,
+            player: H160,
+            team: Option<u32>,
+            credential: Option<EntryCredential>,
+        ) -> Result<()> {
+            // Check game state and registration deadline
+            let now = self.now();
+            let late_entry = match self.game_state {
+                GameState::AcceptingDeposits => {
+                    if now >= self.registration_deadline {
+                        return Err(Error::RegistrationClosed);
+                    }
+                    false
+                }
+                GameState::InProgress if self.late_registration_period.is_some() => {
+                    if self.get_late_registration_time_remaining() == Some(0) {
+                        return Err(Error::RegistrationClosed);
+                    }
+                    true
+                }
+                _ => return Err(Error::GameNotInCorrectState),
+            };
+
+            let caller = self.env().caller();
+            let payer = (caller != player).then_some(caller);
+
+            // Check correct deposit amount
+            let required_amount = if late_entry {
+                self.get_late_buy_in_amount()
+            } else {
+                self.deposit_buy_in()?
+            };
+            let entry_fee = self.entry_fee;
+            self.check_payment(required_amount.saturating_add(entry_fee))?;
+
+            // Check if player already deposited
+            if self.is_player_registered(player) {
+                return Err(Error::PlayerAlreadyDeposited);
+            }
+
+            // Check entry gate
+            self.check_entry_gate(player, credential.as_ref())?;
+
+            // Check rating band
+            self.check_rating_band(player)?;
+
+            // Check if game is full
+            if let Some(max_players) = self.max_players {
+                if self.player_count >= max_players {
+                    return Err(Error::GameFull);
+                }
+            }
+
+            // Check team choice
+            let team_size = match (self.team_config, team) {
+                (Some(config), Some(team)) => {
+                    if team >= config.team_count {
+                        return Err(Error::InvalidTeam);
+                    }
+                    let size = self.get_team_size(team);
+                    if size >= config.max_team_size {
+                        return Err(Error::TeamFull);
+                    }
+                    size
+                }
+                (Some(_), None) => return Err(Error::TeamRequired),
+                (None, Some(_)) => return Err(Error::NotTeamGame),
+                (None, None) => 0,
+            };
+
+            // Pull token buy-ins from the caller
+            self.collect_payment(caller, required_amount.saturating_add(entry_fee))?;
+
+            // Add player
+            self.seat_player(
+                player,
+                payer,
+                required_amount,
+                entry_fee,
+                late_entry,
+                team.map(|team| (team, team_size)),
+            );
+
+            // Emit PlayerJoined event (commented for MVP due to ink! v6 compatibility)
+            // self.env().emit_event(PlayerJoined {
+            //     player,
+            //     player_count: self.player_count,
+            //     prize_pool: self.prize_pool,
             // });
 
-            // Reset game state
-            self.reset_game_state();
+            // Try to begin game if conditions are met
+            self.try_begin_game()?;
+
+            Ok(())
+        }
+
+        /// Internal function converting a pegged buy-in with the oracle's latest price
+        fn quote_pegged_buy_in(&self, peg: &PeggedBuyIn) -> Result<Balance> {
+            let price = self.latest_price(peg.oracle)?;
+            pegged_amount(peg, price, self.env().block_timestamp())
+        }
+
+        /// Internal function reading `(price, updated_at)` from an oracle
+        fn latest_price(&self, oracle: H160) -> Result<(u128, u64)> {
+            let result = build_call::<Environment>()
+                .call(oracle)
+                .exec_input(ExecutionInput::new(Selector::new(LATEST_PRICE_SELECTOR)))
+                .returns::<(u128, u64)>()
+                .try_invoke();
+            match result {
+                Ok(Ok(price)) => Ok(price),
+                _ => Err(Error::OracleCallFailed),
+            }
+        }
+
+        /// Internal function returning the buy-in the current deposit has to pay
+        ///
+        /// Games re-quoting per deposit accept the transferred stake if it is within tolerance.
+        fn deposit_buy_in(&self) -> Result<Balance> {
+            let (peg, tolerance_bps) = match self.pegged_buy_in {
+                Some(peg) => match peg.slippage_bps {
+                    Some(bps) => (peg, bps),
+                    None => return Ok(self.buy_in_amount),
+                },
+                None => return Ok(self.buy_in_amount),
+            };
+
+            let quoted = self.quote_pegged_buy_in(&peg)?;
+            let transferred: Balance = self.env().transferred_value().try_into().unwrap_or(0);
+            let amount = transferred.saturating_sub(self.entry_fee);
+            if !within_tolerance(amount, quoted, tolerance_bps) {
+                return Err(Error::SlippageExceeded);
+            }
+            Ok(amount)
+        }
+
+        /// Internal function handing a seat to a new holder while deposits are open
+        ///
+        /// The new holder must pass the same entry checks as a depositor.
+        fn move_seat(
+            &mut self,
+            from: H160,
+            to: H160,
+            credential: Option<&EntryCredential>,
+        ) -> Result<()> {
+            if self.game_state != GameState::AcceptingDeposits {
+                return Err(Error::GameNotInCorrectState);
+            }
+            let mut info = self
+                .get_player_info(from)
+                .ok_or(Error::PlayerNotRegistered)?;
+            if to == from || self.is_player_registered(to) {
+                return Err(Error::PlayerAlreadyDeposited);
+            }
+            // A seat someone else paid for only moves on its payer's say, so its refund stays theirs
+            if info.payer.is_some_and(|payer| payer != self.env().caller()) {
+                return Err(Error::NotAuthorized);
+            }
+            self.check_entry_gate(to, credential)?;
+            self.check_rating_band(to)?;
+
+            // Move the record, the old holder's session key and referral do not carry over
+            self.players.remove(from);
+            self.seat_offers.remove((self.current_round, from));
+            info.session_key = None;
+            info.referrer = None;
+            self.players.insert(to, &info);
+            if let Some(ticket) = info.ticket {
+                self.move_ticket(ticket, from, to);
+            }
+
+            // Replace the holder wherever the seat is listed
+            let round = self.current_round;
+            if let Some(index) =
+                (0..self.roster_len).find(|index| self.roster.get((round, *index)) == Some(from))
+            {
+                self.roster.insert((round, index), &to);
+            }
+            if let Some(team) = info.team {
+                if let Some(index) = (0..self.get_team_size(team))
+                    .find(|index| self.team_members.get((round, team, *index)) == Some(from))
+                {
+                    self.team_members.insert((round, team, index), &to);
+                }
+            }
+            if let Some(id) = self
+                .tournament
+                .as_ref()
+                .filter(|t| t.stage == 0)
+                .map(|t| t.id)
+            {
+                let size = self.get_tournament_stage_size(1);
+                if let Some(index) = (0..size)
+                    .find(|index| self.tournament_entrants.get((id, 1, *index)) == Some(from))
+                {
+                    self.tournament_entrants.insert((id, 1, index), &to);
+                }
+            }
+
+            // The buy-in follows the seat in lifetime statistics
+            self.update_stats(from, |stats| {
+                stats.games_played = stats.games_played.saturating_sub(1);
+                stats.total_buy_ins = stats.total_buy_ins.saturating_sub(info.paid);
+            });
+            self.update_stats(to, |stats| {
+                stats.games_played = stats.games_played.saturating_add(1);
+                stats.total_buy_ins = stats.total_buy_ins.saturating_add(info.paid);
+            });
+
+            Ok(())
+        }
+
+        /// Internal function minting the ticket for a new seat
+        fn mint_ticket(&mut self, owner: H160, buy_in: Balance) -> TicketId {
+            let ticket = self.next_ticket_id;
+            self.next_ticket_id = self.next_ticket_id.saturating_add(1);
+            self.tickets.insert(
+                ticket,
+                &Ticket {
+                    owner,
+                    round: self.current_round,
+                    buy_in,
+                },
+            );
+            self.ticket_balances
+                .insert(owner, &self.ticket_balance_of(owner).saturating_add(1));
+            ticket
+        }
+
+        /// Internal function changing a ticket's owner, clearing its approval
+        fn move_ticket(&mut self, ticket: TicketId, from: H160, to: H160) {
+            if let Some(mut info) = self.tickets.get(ticket) {
+                info.owner = to;
+                self.tickets.insert(ticket, &info);
+                self.ticket_approvals.remove(ticket);
+                self.ticket_balances
+                    .insert(from, &self.ticket_balance_of(from).saturating_sub(1));
+                self.ticket_balances
+                    .insert(to, &self.ticket_balance_of(to).saturating_add(1));
+            }
+        }
+
+        /// Internal function burning the tickets `[first, end)` that still exist
+        fn burn_ticket_range(&mut self, (first, end): (TicketId, TicketId)) {
+            for ticket in first..end {
+                self.burn_ticket(ticket);
+            }
+        }
+
+        /// Internal function burning a ticket once its seat is gone
+        fn burn_ticket(&mut self, ticket: TicketId) {
+            if let Some(info) = self.tickets.take(ticket) {
+                self.ticket_approvals.remove(ticket);
+                self.ticket_balances.insert(
+                    info.owner,
+                    &self.ticket_balance_of(info.owner).saturating_sub(1),
+                );
+            }
+        }
+
+        /// Internal function checking a player's rating is within the game's band
+        fn check_rating_band(&self, player: H160) -> Result<()> {
+            if let Some((min_rating, max_rating)) = self.rating_band {
+                let rating = self.get_rating(player);
+                if rating < min_rating || rating > max_rating {
+                    return Err(Error::RatingOutOfBand);
+                }
+            }
+            Ok(())
+        }
+
+        /// Internal function checking the caller may enter the current game
+        fn check_entry_gate(
+            &self,
+            player: H160,
+            credential: Option<&EntryCredential>,
+        ) -> Result<()> {
+            match self.entry_gate {
+                EntryGate::Open => Ok(()),
+                EntryGate::Allowlist => {
+                    if self.is_allowlisted(self.current_round, player) {
+                        Ok(())
+                    } else {
+                        Err(Error::NotAllowlisted)
+                    }
+                }
+                EntryGate::MerkleRoot(root) => {
+                    let proof = match credential {
+                        Some(EntryCredential::MerkleProof(proof)) => proof,
+                        _ => return Err(Error::InvalidMerkleProof),
+                    };
+                    let node = proof
+                        .iter()
+                        .fold(keccak256(player.as_bytes()), |node, sibling| {
+                            hash_pair(&node, sibling)
+                        });
+                    if node == root {
+                        Ok(())
+                    } else {
+                        Err(Error::InvalidMerkleProof)
+                    }
+                }
+                EntryGate::Passcode(hash) => match credential {
+                    Some(EntryCredential::Passcode(passcode)) if keccak256(passcode) == hash => {
+                        Ok(())
+                    }
+                    _ => Err(Error::InvalidPasscode),
+                },
+            }
+        }
+
+        /// Internal function adding a paid player to the current round
+        ///
+        /// `team` is the (team, current team size) pair in team games.
+        fn seat_player(
+            &mut self,
+            player: H160,
+            payer: Option<H160>,
+            amount: Balance,
+            entry_fee: Balance,
+            late_entry: bool,
+            team: Option<(u32, u32)>,
+        ) {
+            let bounty = self.add_buy_in(amount);
+            let ticket = self.mint_ticket(player, amount);
+
+            // Entry fees go to the fee ledger, not the prize pool
+            self.entry_fees = self.entry_fees.saturating_add(entry_fee);
+            self.total_entry_fees = self.total_entry_fees.saturating_add(entry_fee);
+
+            self.players.insert(
+                player,
+                &PlayerInfo {
+                    round: self.current_round,
+                    late_entry,
+                    entries: 1,
+                    bounty,
+                    team: team.map(|(team, _)| team),
+                    paid: amount,
+                    payer,
+                    ticket: Some(ticket),
+                    entry_fee,
+                    referrer: self.referrers.get(player),
+                    ..Default::default()
+                },
+            );
+            self.roster
+                .insert((self.current_round, self.roster_len), &player);
+            self.roster_len = self.roster_len.saturating_add(1);
+            self.update_stats(player, |stats| {
+                stats.games_played = stats.games_played.saturating_add(1);
+                stats.total_buy_ins = stats.total_buy_ins.saturating_add(amount);
+            });
+            if let Some(tournament) = self.tournament.as_ref().filter(|t| t.stage == 0) {
+                let id = tournament.id;
+                self.add_tournament_entrant(id, 1, player);
+            }
+            if let Some((team, team_size)) = team {
+                self.team_members
+                    .insert((self.current_round, team, team_size), &player);
+                self.team_sizes
+                    .insert((self.current_round, team), &team_size.saturating_add(1));
+            }
+            self.player_count = self.player_count.saturating_add(1);
+            if late_entry {
+                self.alive_count = self.alive_count.saturating_add(1);
+            }
+        }
+
+        /// Buy back into a running game after being eliminated
+        #[ink(message, payable)]
+        pub fn rebuy(&mut self) -> Result<()> {
+            // Check game state
+            if self.game_state != GameState::InProgress {
+                return Err(Error::GameNotInCorrectState);
+            }
+
+            let config = self.rebuy_config.ok_or(Error::RebuyNotAllowed)?;
+            let caller = self.env().caller();
+            let mut info = self
+                .get_player_info(caller)
+                .ok_or(Error::PlayerNotRegistered)?;
+
+            // Check correct re-buy amount
+            self.check_payment(config.amount)?;
+
+            // Check per-player and per-game limits (the first entry is not a re-buy)
+            if info.entries.saturating_sub(1) >= config.max_per_player
+                || self.rebuy_count >= config.max_per_game
+            {
+                return Err(Error::RebuyLimitReached);
+            }
+
+            self.collect_payment(caller, config.amount)?;
+
+            // Re-buys bring an eliminated player back into the game
+            if let Some(order) = info.elimination_order.take() {
+                self.elimination_log.remove((self.current_round, order));
+                self.alive_count = self.alive_count.saturating_add(1);
+            }
+
+            // Record the entry and grow the prize pool
+            info.entries = info.entries.saturating_add(1);
+            info.bounty = info.bounty.saturating_add(self.add_buy_in(config.amount));
+            info.paid = info.paid.saturating_add(config.amount);
+            self.players.insert(caller, &info);
+            self.update_stats(caller, |stats| {
+                stats.total_buy_ins = stats.total_buy_ins.saturating_add(config.amount);
+            });
+            self.rebuy_count = self.rebuy_count.saturating_add(1);
+
+            Ok(())
+        }
+
+        /// Set or clear the session key allowed to act for the caller in this round
+        #[ink(message)]
+        pub fn set_session_key(&mut self, session_key: Option<H160>) -> Result<()> {
+            let caller = self.env().caller();
+            let mut info = self
+                .get_player_info(caller)
+                .ok_or(Error::PlayerNotRegistered)?;
+            info.session_key = session_key;
+            self.players.insert(caller, &info);
+            Ok(())
+        }
+
+        /// Request a mid-game cash-out (player or their session key, cash games only)
+        #[ink(message)]
+        pub fn request_cash_out(&mut self, player: H160) -> Result<()> {
+            // Check game state and mode
+            if self.game_state != GameState::InProgress {
+                return Err(Error::GameNotInCorrectState);
+            }
+            if !self.cash_game {
+                return Err(Error::NotCashGame);
+            }
+
+            let mut info = self
+                .get_player_info(player)
+                .ok_or(Error::PlayerNotRegistered)?;
+
+            // Only the player or their session key may request
+            let caller = self.env().caller();
+            if caller != player && info.session_key != Some(caller) {
+                return Err(Error::NotAuthorized);
+            }
+
+            info.cash_out_requested = true;
+            self.players.insert(player, &info);
+            Ok(())
+        }
+
+        /// Pay out a requested cash-out at the attested value (GameServer only)
+        ///
+        /// The admin fee is taken from the attested value and the player leaves the game.
+        #[ink(message)]
+        pub fn attest_cash_out(&mut self, player: H160, value: Balance) -> Result<()> {
+            self.ensure_role(Role::GameServer)?;
+
+            // Check game state
+            if self.game_state != GameState::InProgress {
+                return Err(Error::GameNotInCorrectState);
+            }
+
+            let info = self
+                .get_player_info(player)
+                .ok_or(Error::PlayerNotRegistered)?;
+            if !info.cash_out_requested {
+                return Err(Error::CashOutNotRequested);
+            }
+            if value > self.prize_pool {
+                return Err(Error::InsufficientPrizePool);
+            }
+
+            // Remove the player and their value from the pool
+            self.players.remove(player);
+            if let Some(ticket) = info.ticket {
+                self.burn_ticket(ticket);
+            }
+            self.player_count = self.player_count.saturating_sub(1);
+            if info.elimination_order.is_none() {
+                self.alive_count = self.alive_count.saturating_sub(1);
+            }
+            self.prize_pool = self.prize_pool.saturating_sub(value);
+
+            // Leaving players keep their own unclaimed bounty
+            self.bounty_pool = self.bounty_pool.saturating_sub(info.bounty);
+            self.credit_in_game_currency(player, info.bounty);
+
+            // Calculate admin fee on exit
+            let admin_cut = self.pool_fee(value);
+            let payout = value.saturating_sub(admin_cut);
+            let admin_cut =
+                admin_cut.saturating_sub(self.pay_referral_share(info.referrer, admin_cut));
+
+            self.pay_prize(player, payout)?;
+            self.collect_admin_fee(admin_cut)?;
+
+            Ok(())
+        }
+
+        /// Report eliminations as (eliminator, eliminated) pairs (GameServer only)
+        ///
+        /// The eliminated player's bounty is credited to the eliminator's claimable balance.
+        /// Once a single player is left alive the game moves to `WaitingForResults`.
+        #[ink(message)]
+        pub fn report_eliminations(&mut self, eliminations: Vec<(H160, H160)>) -> Result<()> {
+            self.ensure_role(Role::GameServer)?;
+
+            // Check game state
+            if self.game_state != GameState::InProgress {
+                return Err(Error::GameNotInCorrectState);
+            }
+
+            for (eliminator, eliminated) in eliminations {
+                // Both players must be alive
+                if eliminator == eliminated || !self.is_player_alive(eliminator) {
+                    return Err(Error::InvalidElimination);
+                }
+                let mut info = self
+                    .get_player_info(eliminated)
+                    .ok_or(Error::InvalidElimination)?;
+                if info.elimination_order.is_some() {
+                    return Err(Error::InvalidElimination);
+                }
+
+                // Record the elimination order
+                self.elimination_count = self.elimination_count.saturating_add(1);
+                info.elimination_order = Some(self.elimination_count);
+                self.elimination_log
+                    .insert((self.current_round, self.elimination_count), &eliminated);
+                self.alive_count = self.alive_count.saturating_sub(1);
+
+                // Move the bounty to the eliminator
+                let bounty = info.bounty;
+                info.bounty = 0;
+                info.bounty_paid = info.bounty_paid.saturating_add(bounty);
+                self.players.insert(eliminated, &info);
+                self.bounty_pool = self.bounty_pool.saturating_sub(bounty);
+                self.credit_in_game_currency(eliminator, bounty);
+                self.record_winnings(eliminator, bounty);
+            }
+
+            // Last player standing ends the game
+            if self.alive_count <= 1 {
+                let now = self.now();
+                self.enter_waiting_for_results(now);
+            }
+
+            Ok(())
+        }
+
+        /// Withdraw the caller's claimable balance
+        #[ink(message)]
+        pub fn claim(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            let amount = self.claimable.take(caller).unwrap_or(0);
+            if amount == 0 {
+                return Err(Error::NothingToClaim);
+            }
+
+            self.env()
+                .transfer(caller, amount.into())
+                .map_err(|_| Error::TransferFailed)?;
+
+            Ok(())
+        }
+
+        /// Withdraw the caller's claimable balance of a token
+        #[ink(message)]
+        pub fn claim_token(&mut self, token: H160) -> Result<()> {
+            let caller = self.env().caller();
+            let amount = self.token_claimable.take((token, caller)).unwrap_or(0);
+            if amount == 0 {
+                return Err(Error::NothingToClaim);
+            }
+
+            self.token_transfer(token, caller, amount)
+        }
+
+        /// Try to begin the game if conditions are met
+        #[ink(message)]
+        pub fn try_begin_game(&mut self) -> Result<()> {
+            // Only work if in AcceptingDeposits state
+            if self.game_state != GameState::AcceptingDeposits {
+                return Ok(()); // Not an error, just nothing to do
+            }
+
+            let now = self.now();
+
+            // Check if registration deadline has passed
+            if now >= self.registration_deadline {
+                if self.has_enough_players() {
+                    // Start the game
+                    self.begin_game(now);
+                } else {
+                    // Not enough players, refund everyone
+                    self.refund_all_players()?;
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Check game conditions and handle automatic state transitions
+        #[ink(message)]
+        pub fn check_game_conditions(&mut self) -> Result<()> {
+            let now = self.now();
+
+            match self.game_state {
+                GameState::AcceptingDeposits => {
+                    // Check if registration deadline passed
+                    if now >= self.registration_deadline {
+                        if self.has_enough_players() {
+                            self.begin_game(now);
+                        } else {
+                            // Refund all players and reset
+                            self.refund_all_players()?;
+                        }
+                    }
+                }
+                GameState::InProgress => {
+                    // Check if game duration exceeded
+                    if let Some(duration) = self.game_duration {
+                        if now >= self.game_start_time + duration {
+                            self.enter_waiting_for_results(now);
+                            // Emit GameTimeExpired event (commented for MVP due to ink! v6 compatibility)
+                            // self.env().emit_event(GameTimeExpired {
+                            //     game_end_time: now,
+                            // });
+                        }
+                    }
+                }
+                GameState::WaitingForResults => {
+                    // Refund everyone if winners were not submitted in time
+                    if self.settlement_period.is_some() && now >= self.settlement_deadline {
+                        self.refund_all_players_with_reason(GameEndReason::TimeLimit)?;
+                    }
+                }
+                _ => {}
+            }
+            Ok(())
+        }
+
+        /// Report that the game has ended (called by game server or admin)
+        #[ink(message)]
+        pub fn report_game_end(&mut self, reason: GameEndReason) -> Result<()> {
+            // Check state
+            if self.game_state != GameState::InProgress {
+                return Err(Error::GameNotInCorrectState);
+            }
+
+            // Only admin can force end, game server can report natural end
+            match reason {
+                GameEndReason::AdminForced => {
+                    if self.env().caller() != self.game_admin {
+                        return Err(Error::NotAdmin);
+                    }
+                }
+                GameEndReason::TimeLimit | GameEndReason::LastPlayerStanding => {
+                    // Game server can report these, for MVP we'll allow any caller
+                    // In production, you'd want to verify the caller is the authorized game server
+                }
+            }
+
+            // Move to waiting for results
+            let now = self.now();
+            self.enter_waiting_for_results(now);
+
+            // Emit GameTimeExpired event for time-based endings
+            if reason == GameEndReason::TimeLimit {
+                // self.env().emit_event(GameTimeExpired {
+                //     game_end_time: now,
+                // });
+            }
+
+            Ok(())
+        }
+
+        /// Submit winners and distribute prizes (Admin only)
+        #[ink(message)]
+        pub fn submit_winners(
+            &mut self,
+            winners: Vec<H160>,
+            percentages: Vec<u8>,
+            reason: GameEndReason,
+        ) -> Result<()> {
+            // Tournament tables advance placements instead of paying out
+            if self.tournament.as_ref().is_some_and(|t| t.table_running) {
+                return self.settle_tournament_table(winners);
+            }
+
+            // Check access, state and input
+            self.validate_results(winners.len(), &percentages)?;
+            self.check_placements(&winners)?;
+
+            // Calculate admin fee
+            let (admin_cut, winner_pool) = self.split_admin_cut();
+
+            // Store total for event
+            let total_distributed = self.prize_pool;
+
+            // Distribute prizes to winners
+            for (winner, percentage) in winners.iter().zip(percentages.iter()) {
+                let prize = winner_pool
+                    .saturating_mul(*percentage as Balance)
+                    .checked_div(100)
+                    .unwrap_or(0);
+                self.pay_prize(*winner, prize)?;
+            }
+            self.record_win(winners[0]);
+
+            // Pay referrers, then transfer admin fee
+            let admin_cut = self.pay_referral_shares(admin_cut);
+            self.collect_admin_fee(admin_cut)?;
+
+            // Mint trophies and award badges
+            let podium: Vec<(H160, u32)> = winners.iter().copied().zip(0..).collect();
+            self.mint_trophies(&podium, total_distributed, reason);
+            self.award_badges(&winners[..1], total_distributed);
+
+            // Update skill ratings from the final placements
+            let placements = self.settlement_placements(&winners);
+            self.update_ratings(&placements);
+
+            // Award season points by placement
+            self.award_season_points(&podium);
+
+            // First place wins the jackpot in large enough games
+            self.trigger_jackpot(&winners[..1], self.player_count);
+
+            // Emit GameEnded event (commented for MVP due to ink! v6 compatibility)
+            // self.env().emit_event(GameEnded {
+            //     total_distributed,
+            //     winners: winners.clone(),
+            //     percentages: percentages.clone(),
+            //     admin_fee: admin_cut,
+            //     reason,
+            // });
+
+            // Reset game state
+            self.reset_game_state();
+
+            Ok(())
+        }
+
+        /// Report per-player contributions used for team splits (GameServer only)
+        #[ink(message)]
+        pub fn report_contributions(&mut self, contributions: Vec<(H160, u32)>) -> Result<()> {
+            self.ensure_role(Role::GameServer)?;
+
+            // Check state
+            if !matches!(
+                self.game_state,
+                GameState::InProgress | GameState::WaitingForResults
+            ) {
+                return Err(Error::GameNotInCorrectState);
+            }
+            if self.team_config.is_none() {
+                return Err(Error::NotTeamGame);
+            }
+
+            for (player, contribution) in contributions {
+                let mut info = self
+                    .get_player_info(player)
+                    .ok_or(Error::PlayerNotRegistered)?;
+                info.contribution = contribution;
+                self.players.insert(player, &info);
+            }
+
+            Ok(())
+        }
+
+        /// Submit team ranking and distribute prizes among team members (Admin only)
+        ///
+        /// Each team's share is split equally or by reported contribution. Members share their
+        /// team's placement for trophies, season points and ratings.
+        #[ink(message)]
+        pub fn submit_team_results(
+            &mut self,
+            teams: Vec<u32>,
+            percentages: Vec<u8>,
+            reason: GameEndReason,
+        ) -> Result<()> {
+            let config = self.team_config.ok_or(Error::NotTeamGame)?;
+
+            // Check access, state and input
+            self.validate_results(teams.len(), &percentages)?;
+            if teams.iter().any(|team| *team >= config.team_count) {
+                return Err(Error::InvalidTeam);
+            }
+            if teams
+                .iter()
+                .enumerate()
+                .any(|(index, team)| teams[..index].contains(team))
+            {
+                return Err(Error::DuplicateTeam);
+            }
+
+            // A ranked team without members would strand its share
+            let rosters: Vec<Vec<H160>> = teams
+                .iter()
+                .map(|team| self.get_team_members(*team))
+                .collect();
+            if rosters.iter().any(|members| members.is_empty()) {
+                return Err(Error::InvalidTeam);
+            }
+            let podium: Vec<(H160, u32)> = rosters
+                .iter()
+                .zip(0..)
+                .flat_map(|(members, rank)| members.iter().map(move |member| (*member, rank)))
+                .collect();
+            let ranked: Vec<H160> = podium.iter().map(|(member, _)| *member).collect();
+            self.check_placements(&ranked)?;
+
+            // Calculate admin fee
+            let (admin_cut, winner_pool) = self.split_admin_cut();
+
+            // Store total for trophies and badges
+            let total_distributed = self.prize_pool;
+
+            // Distribute each team's share among its current members
+            for ((team, percentage), members) in
+                teams.iter().zip(percentages.iter()).zip(rosters.iter())
+            {
+                let team_share = winner_pool
+                    .saturating_mul(*percentage as Balance)
+                    .checked_div(100)
+                    .unwrap_or(0);
+                let total_contribution: Balance = members
+                    .iter()
+                    .filter_map(|member| self.get_player_info(*member))
+                    .map(|info| info.contribution as Balance)
+                    .sum();
+
+                for member in members.iter() {
+                    let prize = match config.split {
+                        TeamSplit::Contribution if total_contribution > 0 => {
+                            let contribution = self
+                                .get_player_info(*member)
+                                .map(|info| info.contribution)
+                                .unwrap_or(0);
+                            team_share
+                                .saturating_mul(contribution as Balance)
+                                .checked_div(total_contribution)
+                                .unwrap_or(0)
+                        }
+                        _ => team_share
+                            .checked_div(members.len() as Balance)
+                            .unwrap_or(0),
+                    };
+                    self.pay_prize(*member, prize)?;
+                    if team == &teams[0] {
+                        self.record_win(*member);
+                    }
+                }
+            }
+
+            // The winning team shares the jackpot in large enough games
+            self.trigger_jackpot(&rosters[0], self.player_count);
+
+            // Pay referrers, then transfer admin fee
+            let admin_cut = self.pay_referral_shares(admin_cut);
+            self.collect_admin_fee(admin_cut)?;
+
+            // Mint trophies and award badges to the ranked teams' members
+            self.mint_trophies(&podium, total_distributed, reason);
+            self.award_badges(&rosters[0], total_distributed);
+
+            // Update skill ratings and season points from the team placements
+            let placements = self.team_placements(&teams);
+            self.update_ratings(&placements);
+            self.award_season_points(&podium);
+
+            // Reset game state
+            self.reset_game_state();
+
+            Ok(())
+        }
+
+        /// Open tournament registration through the normal deposit flow (Admin only)
+        ///
+        /// When registration closes, entrants are split into tables of `table_size`; the top
+        /// `advance_per_table` placements of each table advance until a single final table
+        /// is paid according to `prize_structure`.
+        #[ink(message)]
+        pub fn start_tournament(
+            &mut self,
+            config: GameConfig,
+            table_size: u32,
+            advance_per_table: u32,
+            prize_structure: Vec<u8>,
+        ) -> Result<()> {
+            if self.tournament.is_some() {
+                return Err(Error::TournamentInProgress);
+            }
+
+            // Validate bracket parameters
+            let total_percentage: u32 = prize_structure.iter().map(|p| *p as u32).sum();
+            if table_size < 2
+                || advance_per_table == 0
+                || advance_per_table >= table_size
+                || prize_structure.is_empty()
+                || total_percentage > 100
+                || config.teams.is_some()
+                || config.currency != Currency::Native
+                || config.entry_fee > 0
+            {
+                return Err(Error::InvalidTournamentConfig);
+            }
+
+            // Registration is a regular game
+            self.start_game_with_config(config)?;
+
+            self.tournament_count = self.tournament_count.saturating_add(1);
+            self.tournament = Some(Tournament {
+                id: self.tournament_count,
+                table_size,
+                advance_per_table,
+                prize_structure,
+                tickets: (self.next_ticket_id, self.next_ticket_id),
+                ..Default::default()
+            });
+
+            Ok(())
+        }
+
+        /// Seat the next table of the current tournament stage and begin its game (Admin only)
+        #[ink(message)]
+        pub fn start_tournament_table(&mut self) -> Result<()> {
+            // Check admin access
+            if self.env().caller() != self.game_admin {
+                return Err(Error::NotAdmin);
+            }
+
+            let mut tournament = self.tournament.clone().ok_or(Error::NoTournament)?;
+            if tournament.stage == 0 || tournament.table_running {
+                return Err(Error::GameNotInCorrectState);
+            }
+            if self.game_state != GameState::Inactive {
+                return Err(Error::GameNotInCorrectState);
+            }
+
+            // Balance table sizes across the remaining tables of the stage
+            let stage_size = self.get_tournament_stage_size(tournament.stage);
+            let remaining = stage_size.saturating_sub(tournament.seated);
+            let tables_left = remaining.div_ceil(tournament.table_size);
+            let table_players = remaining.div_ceil(tables_left.max(1));
+            tournament.final_table = tournament.seated == 0 && stage_size <= tournament.table_size;
+
+            // Seat the players into a fresh arena game
+            self.current_round = self.current_round.saturating_add(1);
+            for index in tournament.seated..tournament.seated.saturating_add(table_players) {
+                if let Some(player) =
+                    self.tournament_entrants
+                        .get((tournament.id, tournament.stage, index))
+                {
+                    self.players.insert(
+                        player,
+                        &PlayerInfo {
+                            round: self.current_round,
+                            entries: 1,
+                            ..Default::default()
+                        },
+                    );
+                }
+            }
+            tournament.seated = tournament.seated.saturating_add(table_players);
+            tournament.table_running = true;
+            self.round_fee_split = tournament.fee_split;
+            self.tournament = Some(tournament);
+
+            self.player_count = table_players;
+            let now = self.now();
+            self.begin_game(now);
+
+            Ok(())
+        }
+
+        /// Cancel the running tournament and credit its pool back to the entrants (Admin only)
+        #[ink(message)]
+        pub fn cancel_tournament(&mut self) -> Result<()> {
+            // Check admin access
+            if self.env().caller() != self.game_admin {
+                return Err(Error::NotAdmin);
+            }
+
+            self.abort_tournament()
+        }
+
+        /// Internal function ending the tournament and crediting its pool back to the entrants
+        fn abort_tournament(&mut self) -> Result<()> {
+            // During registration the buy-ins are refunded like a regular game
+            if self.tournament.as_ref().ok_or(Error::NoTournament)?.stage == 0 {
+                return self.refund_all_players();
+            }
+            let tournament = self.tournament.take().ok_or(Error::NoTournament)?;
+
+            // The overlay was never paid out, return it to the treasury
+            self.treasury_balance = self.treasury_balance.saturating_add(tournament.overlay);
+            self.total_overlay = self.total_overlay.saturating_sub(tournament.overlay);
+
+            // Every stake goes back to whoever paid it
+            for index in 0..tournament.refund_count {
+                if let Some((account, amount)) = self.tournament_refunds.get((tournament.id, index))
+                {
+                    self.credit(account, amount);
+                    self.record_refund(account, amount);
+                }
+            }
+
+            if tournament.table_running {
+                self.reset_game_state();
+            }
+            self.burn_ticket_range(tournament.tickets);
+
+            Ok(())
+        }
+
+        /// Add sponsor funds to the prize pool of a game without playing
+        ///
+        /// `round` must be the current round; contributions are returned if the game is refunded.
+        #[ink(message, payable)]
+        pub fn sponsor_pool(&mut self, round: u32) -> Result<()> {
+            if round != self.current_round {
+                return Err(Error::InvalidRound);
+            }
+
+            // Check game state
+            if !matches!(
+                self.game_state,
+                GameState::AcceptingDeposits | GameState::InProgress
+            ) {
+                return Err(Error::GameNotInCorrectState);
+            }
+            if self.currency != Currency::Native {
+                return Err(Error::UnsupportedCurrency);
+            }
+            // Tournament pools are settled and refunded by the tournament, not per round
+            if self.tournament.is_some() {
+                return Err(Error::TournamentInProgress);
+            }
+
+            let amount: Balance = self.env().transferred_value().try_into().unwrap_or(0);
+            if amount == 0 {
+                return Err(Error::NoValueTransferred);
+            }
+
+            // Record the sponsor once per round
+            let sponsor = self.env().caller();
+            let previous = self.get_sponsor_contribution(round, sponsor);
+            if previous == 0 {
+                self.sponsors.insert((round, self.sponsor_count), &sponsor);
+                self.sponsor_count = self.sponsor_count.saturating_add(1);
+            }
+            self.sponsor_contributions
+                .insert((round, sponsor), &previous.saturating_add(amount));
+            self.sponsored_amount = self.sponsored_amount.saturating_add(amount);
+            self.prize_pool = self.prize_pool.saturating_add(amount);
+
+            Ok(())
+        }
+
+        /// Add funds to the treasury backing guaranteed prize pools
+        #[ink(message, payable)]
+        pub fn fund_treasury(&mut self) -> Result<()> {
+            let amount: Balance = self.env().transferred_value().try_into().unwrap_or(0);
+            if amount == 0 {
+                return Err(Error::NoValueTransferred);
+            }
+            self.treasury_balance = self.treasury_balance.saturating_add(amount);
+            Ok(())
+        }
+
+        /// Withdraw unreserved treasury funds (Admin only)
+        #[ink(message)]
+        pub fn withdraw_treasury(&mut self, amount: Balance) -> Result<()> {
+            // Check admin access
+            if self.env().caller() != self.game_admin {
+                return Err(Error::NotAdmin);
+            }
+            if amount > self.treasury_balance {
+                return Err(Error::InsufficientTreasury);
+            }
+
+            self.treasury_balance = self.treasury_balance.saturating_sub(amount);
+            self.env()
+                .transfer(self.game_admin, amount.into())
+                .map_err(|_| Error::TransferFailed)?;
+
+            Ok(())
+        }
+
+        /// Configure the jackpot slice and win condition (Admin only)
+        #[ink(message)]
+        pub fn set_jackpot_config(&mut self, config: JackpotConfig) -> Result<()> {
+            // Check admin access
+            if self.env().caller() != self.game_admin {
+                return Err(Error::NotAdmin);
+            }
+            if config.percentage > 100 {
+                return Err(Error::InvalidPercentages);
+            }
+            self.jackpot_config = config;
+            Ok(())
+        }
+
+        /// Award the jackpot for a server-attested rare event (GameServer only)
+        #[ink(message)]
+        pub fn award_jackpot(&mut self, player: H160) -> Result<()> {
+            self.ensure_role(Role::GameServer)?;
+
+            // Check state
+            if !matches!(
+                self.game_state,
+                GameState::InProgress | GameState::WaitingForResults
+            ) {
+                return Err(Error::GameNotInCorrectState);
+            }
+            if !self.is_player_registered(player) {
+                return Err(Error::PlayerNotRegistered);
+            }
+            if self.jackpot == 0 {
+                return Err(Error::JackpotEmpty);
+            }
+
+            self.pay_jackpot(&[player]);
+            Ok(())
+        }
+
+        /// Start a season (Admin only)
+        ///
+        /// `start` and `end` are block timestamps in milliseconds.
+        #[ink(message)]
+        pub fn start_season(
+            &mut self,
+            start: Timestamp,
+            end: Timestamp,
+            points_table: Vec<u32>,
+            fee_percentage: u8,
+            payout_percentages: Vec<u8>,
+        ) -> Result<()> {
+            // Check admin access
+            if self.env().caller() != self.game_admin {
+                return Err(Error::NotAdmin);
+            }
+            if self.season.is_some() {
+                return Err(Error::SeasonActive);
+            }
+
+            // Validate season parameters
+            let total_payout: u32 = payout_percentages.iter().map(|p| *p as u32).sum();
+            if end <= start
+                || points_table.is_empty()
+                || payout_percentages.is_empty()
+                || payout_percentages.len() > SEASON_LEADERBOARD_SIZE
+                || total_payout > 100
+                || fee_percentage > 100
+            {
+                return Err(Error::InvalidSeasonConfig);
+            }
+
+            // The pool left unpaid by the previous season carries over
+            self.season_count = self.season_count.saturating_add(1);
+            self.season_leaderboard.set(&Vec::new());
+            self.season = Some(Season {
+                id: self.season_count,
+                start,
+                end,
+                points_table,
+                fee_percentage,
+                payout_percentages,
+                pool: core::mem::take(&mut self.season_carryover),
+            });
+
+            Ok(())
+        }
+
+        /// Pay the season pool to the top of the leaderboard once the season is over
+        #[ink(message)]
+        pub fn end_season(&mut self) -> Result<()> {
+            let season = self.season.clone().ok_or(Error::NoSeason)?;
+            if self.env().block_timestamp() < season.end {
+                return Err(Error::SeasonNotOver);
+            }
+
+            // Credit the top-N players
+            let ranking = self.season_leaderboard.get_or_default();
+            self.season_leaderboard.set(&Vec::new());
+            let mut paid: Balance = 0;
+            for ((player, _), percentage) in ranking.iter().zip(season.payout_percentages.iter()) {
+                let prize = season
+                    .pool
+                    .saturating_mul(*percentage as Balance)
+                    .checked_div(100)
+                    .unwrap_or(0);
+                self.credit(*player, prize);
+                self.record_winnings(*player, prize);
+                paid = paid.saturating_add(prize);
+            }
+
+            // Whatever was not paid out carries over to the next season
+            self.season_carryover = self
+                .season_carryover
+                .saturating_add(season.pool.saturating_sub(paid));
+            self.season = None;
+            Ok(())
+        }
+
+        /// Configure or remove the matchmaking queue of a stake tier (Admin only)
+        #[ink(message)]
+        pub fn set_queue_tier(&mut self, stake: Balance, tier: Option<QueueTier>) -> Result<()> {
+            // Check admin access
+            if self.env().caller() != self.game_admin {
+                return Err(Error::NotAdmin);
+            }
+
+            match tier {
+                Some(tier) => {
+                    if stake == 0 || tier.players_per_game < 2 {
+                        return Err(Error::InvalidQueueTier);
+                    }
+                    self.queue_tiers.insert(stake, &tier);
+                }
+                None => self.queue_tiers.remove(stake),
+            }
+
+            Ok(())
+        }
+
+        /// Join the matchmaking queue of the tier matching the transferred stake
+        ///
+        /// A game is formed and begins as soon as enough compatible players are waiting.
+        #[ink(message, payable)]
+        pub fn join_queue(&mut self) -> Result<()> {
+            let stake: Balance = self.env().transferred_value().try_into().unwrap_or(0);
+            if !self.queue_tiers.contains(stake) {
+                return Err(Error::NoQueueTier);
+            }
+
+            let caller = self.env().caller();
+            if self.queued.contains(caller) {
+                return Err(Error::AlreadyQueued);
+            }
+
+            // Append to the tier's queue
+            let (head, tail) = self.queue_bounds.get(stake).unwrap_or((0, 0));
+            self.queue_entries.insert((stake, tail), &caller);
+            self.queue_bounds
+                .insert(stake, &(head, tail.saturating_add(1)));
+            self.queued.insert(caller, &(stake, tail));
+            self.queue_sizes
+                .insert(stake, &self.get_queue_size(stake).saturating_add(1));
+
+            self.try_match_queue(stake)?;
+
+            Ok(())
+        }
+
+        /// Leave the matchmaking queue and get the full stake back
+        #[ink(message)]
+        pub fn leave_queue(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            let (stake, _) = self.queued.take(caller).ok_or(Error::NotQueued)?;
+            self.queue_sizes
+                .insert(stake, &self.get_queue_size(stake).saturating_sub(1));
+            self.drop_stale_queue_head(stake);
+
+            self.env()
+                .transfer(caller, stake.into())
+                .map_err(|_| Error::TransferFailed)?;
+
+            Ok(())
+        }
+
+        /// Try to form a game from a tier's queue, e.g. once the arena is free again
+        #[ink(message)]
+        pub fn match_queue(&mut self, stake: Balance) -> Result<bool> {
+            self.try_match_queue(stake)
+        }
+
+        /// Force end game and refund all players (Admin only, emergency function)
+        #[ink(message)]
+        pub fn force_end_game(&mut self) -> Result<()> {
+            // Check admin access
+            if self.env().caller() != self.game_admin {
+                return Err(Error::NotAdmin);
+            }
+
+            // Can only force end if game is active
+            if matches!(self.game_state, GameState::Inactive) {
+                return Err(Error::GameNotInCorrectState);
+            }
+
+            // A table cannot be replayed, so the whole tournament is cancelled
+            if self.tournament.is_some() {
+                return self.cancel_tournament();
+            }
+
+            // Refund all players
+            self.refund_all_players()?;
+
+            Ok(())
+        }
+
+        /// Internal function to refund all players
+        fn refund_all_players(&mut self) -> Result<()> {
+            self.refund_all_players_with_reason(GameEndReason::AdminForced)
+        }
+
+        /// Internal function to refund all players with specific reason
+        fn refund_all_players_with_reason(&mut self, _reason: GameEndReason) -> Result<()> {
+            // A refunded tournament game ends the tournament
+            match self.tournament.as_ref().map(|tournament| tournament.stage) {
+                // Registration buy-ins are refunded below like a regular game
+                Some(0) => self.tournament = None,
+                Some(_) => return self.abort_tournament(),
+                None => {}
+            }
+
+            let _total_refunded = self.prize_pool;
+            let _players_refunded = self.player_count;
+
+            // Players still in the game get back what they paid, less bounties already paid out
+            for index in 0..self.roster_len {
+                let player = match self.roster.get((self.current_round, index)) {
+                    Some(player) => player,
+                    None => continue,
+                };
+                if let Some(info) = self.get_player_info(player) {
+                    let entry_fee = if self.keep_entry_fee_on_refund {
+                        0
+                    } else {
+                        info.entry_fee
+                    };
+                    self.entry_fees = self.entry_fees.saturating_sub(entry_fee);
+                    self.total_entry_fees = self.total_entry_fees.saturating_sub(entry_fee);
+                    // The entry fee goes back with the buy-in
+                    let [(buyer, buy_in), (holder, own)] = self.seat_refunds(player, &info);
+                    self.credit_in_game_currency(buyer, buy_in.saturating_add(entry_fee));
+                    self.credit_in_game_currency(holder, own);
+                    self.record_refund(buyer, buy_in);
+                    self.record_refund(holder, own);
+                }
+            }
+
+            // Emit GameRefunded event (commented for MVP due to ink! v6 compatibility)
+            // self.env().emit_event(GameRefunded {
+            //     players_refunded: _players_refunded,
+            //     total_refunded: _total_refunded,
+            //     reason: _reason,
+            // });
+
+            // Sponsors get their contributions back, even if nobody joined
+            for index in 0..self.sponsor_count {
+                if let Some(sponsor) = self.sponsors.get((self.current_round, index)) {
+                    let amount = self
+                        .sponsor_contributions
+                        .get((self.current_round, sponsor))
+                        .unwrap_or(0);
+                    self.credit_in_game_currency(sponsor, amount);
+                }
+            }
+
+            // The overlay was never paid out, return it to the treasury
+            self.treasury_balance = self.treasury_balance.saturating_add(self.overlay);
+            self.total_overlay = self.total_overlay.saturating_sub(self.overlay);
+
+            // Entry fees the game keeps are collected like at settlement
+            let kept_fees = self.entry_fees;
+            self.collect_admin_fee(kept_fees)?;
+
+            // Reset game state
+            self.reset_game_state();
+
+            Ok(())
+        }
+
+        /// Internal function splitting a seat's refund into (buy-in, own re-buys) credits, less
+        /// bounties already paid out
+        ///
+        /// A buy-in paid on the player's behalf goes back to the payer, the player's own re-buys
+        /// absorb the bounty paid out first.
+        fn seat_refunds(&self, player: H160, info: &PlayerInfo) -> [(H160, Balance); 2] {
+            let refundable = info.paid.saturating_sub(info.bounty_paid);
+            match info.payer {
+                Some(payer) => {
+                    let rebuys = self.rebuy_config.map_or(0, |config| {
+                        config
+                            .amount
+                            .saturating_mul(info.entries.saturating_sub(1) as Balance)
+                    });
+                    let own = rebuys.saturating_sub(info.bounty_paid);
+                    [(payer, refundable.saturating_sub(own)), (player, own)]
+                }
+                None => [(player, refundable), (player, 0)],
+            }
+        }
+
+        /// Internal function to validate a result submission (Admin only, WaitingForResults)
+        fn validate_results(&self, recipients: usize, percentages: &[u8]) -> Result<()> {
+            // Check admin access
+            if self.env().caller() != self.game_admin {
+                return Err(Error::NotAdmin);
+            }
+
+            // Check state
+            if self.game_state != GameState::WaitingForResults {
+                return Err(Error::GameNotInCorrectState);
+            }
+
+            // Validate input
+            if recipients == 0 {
+                return Err(Error::NoWinners);
+            }
+
+            if recipients != percentages.len() {
+                return Err(Error::MismatchedData);
+            }
+
+            let total_percentage: u32 = percentages.iter().map(|p| *p as u32).sum();
+            if total_percentage > 100 {
+                return Err(Error::InvalidPercentages);
+            }
+
+            Ok(())
+        }
+
+        /// Check that every placed player holds a seat and is placed only once
+        fn check_placements(&self, placements: &[H160]) -> Result<()> {
+            if placements
+                .iter()
+                .any(|player| !self.is_player_registered(*player))
+            {
+                return Err(Error::PlayerNotRegistered);
+            }
+            if placements
+                .iter()
+                .enumerate()
+                .any(|(index, player)| placements[..index].contains(player))
+            {
+                return Err(Error::DuplicatePlacement);
+            }
+
+            Ok(())
+        }
+
+        /// Internal function to split the prize pool into (admin cut, winner pool)
+        fn split_admin_cut(&mut self) -> (Balance, Balance) {
+            // Bounties nobody collected go back into the prize pool
+            self.prize_pool = self.prize_pool.saturating_add(self.bounty_pool);
+            self.bounty_pool = 0;
+
+            // Entry fees were set aside at deposit, otherwise the fee comes out of the pool
+            let pool_fee = self.pool_fee(self.prize_pool);
+            let admin_cut = pool_fee.saturating_add(self.entry_fees);
+            let winner_pool = self.prize_pool.saturating_sub(pool_fee);
+
+            // Jackpot and season pools are held in the native currency
+            if self.currency != Currency::Native {
+                return (admin_cut, winner_pool);
+            }
+
+            // The jackpot slice is funded from the admin cut
+            let jackpot_cut = self
+                .prize_pool
+                .saturating_mul(self.jackpot_config.percentage as Balance)
+                .checked_div(100)
+                .unwrap_or(0)
+                .min(admin_cut);
+            if jackpot_cut > 0 {
+                self.jackpot = self.jackpot.saturating_add(jackpot_cut);
+                self.jackpot_contributions.insert(
+                    self.jackpot_contribution_count,
+                    &JackpotContribution {
+                        round: self.current_round,
+                        amount: jackpot_cut,
+                    },
+                );
+                self.jackpot_contribution_count = self.jackpot_contribution_count.saturating_add(1);
+            }
+
+            let admin_cut = admin_cut.saturating_sub(jackpot_cut);
+
+            // The season pool slice is funded from the remaining admin cut while the season runs
+            let now = self.env().block_timestamp();
+            if let Some(mut season) = self
+                .season
+                .clone()
+                .filter(|season| now >= season.start && now < season.end)
+            {
+                let season_cut = self
+                    .prize_pool
+                    .saturating_mul(season.fee_percentage as Balance)
+                    .checked_div(100)
+                    .unwrap_or(0)
+                    .min(admin_cut);
+                season.pool = season.pool.saturating_add(season_cut);
+                self.season = Some(season);
+                return (admin_cut.saturating_sub(season_cut), winner_pool);
+            }
+
+            (admin_cut, winner_pool)
+        }
+
+        /// Internal function to award season points by placement (rank 0 is first place)
+        fn award_season_points(&mut self, placements: &[(H160, u32)]) {
+            let season = match &self.season {
+                Some(season) => season.clone(),
+                None => return,
+            };
+            let now = self.env().block_timestamp();
+            if now < season.start || now >= season.end {
+                return;
+            }
+
+            for (player, rank) in placements.iter() {
+                let points = match season.points_table.get(*rank as usize) {
+                    Some(points) => *points,
+                    None => continue,
+                };
+                let total = self
+                    .get_season_points(season.id, *player)
+                    .saturating_add(points);
+                self.season_points.insert((season.id, *player), &total);
+                self.update_season_leaderboard(*player, total);
+            }
+        }
+
+        /// Internal function to keep the top of the season leaderboard sorted by points
+        ///
+        /// Ties keep first-scored order; players falling off the end are dropped.
+        fn update_season_leaderboard(&mut self, player: H160, points: u32) {
+            let mut leaderboard = self.season_leaderboard.get_or_default();
+            match leaderboard.iter_mut().find(|(ranked, _)| *ranked == player) {
+                Some(entry) => entry.1 = points,
+                None => leaderboard.push((player, points)),
+            }
+            leaderboard.sort_by(|a, b| b.1.cmp(&a.1));
+            leaderboard.truncate(SEASON_LEADERBOARD_SIZE);
+            self.season_leaderboard.set(&leaderboard);
+        }
+
+        /// Internal function to pay the jackpot when the win condition is met
+        fn trigger_jackpot(&mut self, winners: &[H160], field_size: u32) {
+            if let Some(min_players) = self.jackpot_config.min_players {
+                if field_size >= min_players {
+                    self.pay_jackpot(winners);
+                }
+            }
+        }
+
+        /// Internal function to split the jackpot equally among winners, dust stays in the jackpot
+        fn pay_jackpot(&mut self, winners: &[H160]) {
+            let share = self
+                .jackpot
+                .checked_div(winners.len() as Balance)
+                .unwrap_or(0);
+            for winner in winners {
+                self.credit(*winner, share);
+                self.record_winnings(*winner, share);
+            }
+            self.jackpot = self
+                .jackpot
+                .saturating_sub(share.saturating_mul(winners.len() as Balance));
+        }
+
+        /// Internal function to rank every player still in the game (lower rank = better)
+        ///
+        /// Winners rank by their position, the rest after them by elimination order; players
+        /// neither placed nor eliminated share the rank right after the winners.
+        fn settlement_placements(&self, winners: &[H160]) -> Vec<(H160, u32)> {
+            let winner_count = winners.len() as u32;
+            (0..self.roster_len)
+                .filter_map(|index| self.roster.get((self.current_round, index)))
+                .filter_map(|player| {
+                    let info = self.get_player_info(player)?;
+                    let rank = match winners.iter().position(|winner| *winner == player) {
+                        Some(position) => position as u32,
+                        None => match info.elimination_order {
+                            Some(order) => winner_count
+                                .saturating_add(1)
+                                .saturating_add(self.elimination_count.saturating_sub(order)),
+                            None => winner_count,
+                        },
+                    };
+                    Some((player, rank))
+                })
+                .collect()
+        }
+
+        /// Internal function to rank every player still in a team game by their team's rank
+        ///
+        /// Members of unranked teams share the rank right after the ranked teams.
+        fn team_placements(&self, teams: &[u32]) -> Vec<(H160, u32)> {
+            let team_count = teams.len() as u32;
+            (0..self.roster_len)
+                .filter_map(|index| self.roster.get((self.current_round, index)))
+                .filter_map(|player| {
+                    let team = self.get_player_info(player)?.team?;
+                    let rank = teams
+                        .iter()
+                        .position(|ranked| *ranked == team)
+                        .map_or(team_count, |position| position as u32);
+                    Some((player, rank))
+                })
+                .collect()
+        }
+
+        /// Internal function to apply Elo updates for a set of placements against the field average
+        fn update_ratings(&mut self, placements: &[(H160, u32)]) {
+            if placements.len() < 2 {
+                return;
+            }
+
+            let ratings: Vec<i64> = placements
+                .iter()
+                .map(|(player, _)| self.get_rating(*player) as i64)
+                .collect();
+            let total_rating: i64 = ratings.iter().sum();
+            let opponents = placements.len() as i64 - 1;
+            let mut ranks: Vec<u32> = placements.iter().map(|(_, rank)| *rank).collect();
+            ranks.sort_unstable();
+
+            // Each player is scored against the field average, keeping settlement O(n log n)
+            for (i, (player, rank)) in placements.iter().enumerate() {
+                let better = ranks.partition_point(|other| other < rank) as i64;
+                let better_or_tied = ranks.partition_point(|other| other <= rank) as i64;
+                let worse = placements.len() as i64 - better_or_tied;
+                let ties = better_or_tied - better - 1;
+
+                // Wins count fully and ties half, as a share of all opponents
+                let actual = SCORE_SCALE * (2 * worse + ties) / (2 * opponents);
+                let field_average = (total_rating - ratings[i]) / opponents;
+                let score_diff = actual - expected_score(ratings[i] - field_average);
+
+                let delta = RATING_K_FACTOR * score_diff / SCORE_SCALE;
+                let new_rating = (ratings[i] + delta).clamp(0, u32::MAX as i64) as u32;
+                self.ratings.insert(*player, &new_rating);
+            }
+        }
+
+        /// Internal function to transfer a prize and record it in the winner's statistics
+        fn pay_prize(&mut self, winner: H160, prize: Balance) -> Result<()> {
+            if prize > 0 {
+                self.send(winner, prize)?;
+                self.record_winnings(winner, prize);
+            }
+            Ok(())
+        }
+
+        /// Internal function to apply an update to a wallet's statistics
+        fn update_stats(&mut self, player: H160, update: impl FnOnce(&mut PlayerStats)) {
+            let mut stats = self.get_player_stats(player);
+            update(&mut stats);
+            self.player_stats.insert(player, &stats);
+        }
+
+        /// Internal function to count a refunded stake against the wallet it went back to
+        fn record_refund(&mut self, account: H160, amount: Balance) {
+            if amount > 0 {
+                self.update_stats(account, |stats| {
+                    stats.refunds = stats.refunds.saturating_add(1);
+                    stats.total_refunded = stats.total_refunded.saturating_add(amount);
+                });
+            }
+        }
+
+        /// Internal function to add to a wallet's lifetime winnings
+        fn record_winnings(&mut self, player: H160, amount: Balance) {
+            if amount > 0 {
+                self.update_stats(player, |stats| {
+                    stats.total_winnings = stats.total_winnings.saturating_add(amount);
+                });
+            }
+        }
+
+        /// Internal function to count a first place
+        fn record_win(&mut self, player: H160) {
+            self.update_stats(player, |stats| {
+                stats.wins = stats.wins.saturating_add(1);
+            });
+        }
+
+        /// Internal function minting trophies to the top placements (rank 0 is first place)
+        fn mint_trophies(
+            &mut self,
+            placements: &[(H160, u32)],
+            pool: Balance,
+            reason: GameEndReason,
+        ) {
+            for (winner, rank) in placements.iter() {
+                if *rank >= self.trophy_places {
+                    continue;
+                }
+                let id = self.trophy_count;
+                self.trophies.insert(
+                    id,
+                    &Trophy {
+                        owner: *winner,
+                        round: self.current_round,
+                        placement: rank.saturating_add(1),
+                        pool,
+                        reason,
+                    },
+                );
+                self.trophy_count = self.trophy_count.saturating_add(1);
+
+                let count = self.get_trophy_count_of(*winner);
+                self.player_trophies.insert((*winner, count), &id);
+                self.player_trophy_counts
+                    .insert(*winner, &count.saturating_add(1));
+            }
+        }
+
+        /// Internal function awarding the winners the badges their statistics now qualify for
+        fn award_badges(&mut self, winners: &[H160], pool: Balance) {
+            let biggest_pool = pool > self.biggest_pool;
+            if biggest_pool {
+                self.biggest_pool = pool;
+            }
+            for winner in winners {
+                let wins = self.get_player_stats(*winner).wins;
+                if wins >= 1 {
+                    self.award_badge(*winner, Badge::FirstWin);
+                }
+                if wins >= 10 {
+                    self.award_badge(*winner, Badge::TenWins);
+                }
+                if biggest_pool {
+                    self.award_badge(*winner, Badge::BiggestPool);
+                }
+            }
+        }
+
+        /// Internal function awarding a badge once
+        fn award_badge(&mut self, player: H160, badge: Badge) {
+            if !self.badges.contains((player, badge)) {
+                self.badges.insert((player, badge), &self.current_round);
+            }
+        }
+
+        /// Internal function deducting a withdrawal from the accrued fees
+        fn take_accrued_fees(&mut self, currency: Currency, amount: Balance) -> Result<()> {
+            let accrued = self.get_accrued_fees(currency);
+            if amount == 0 || amount > accrued {
+                return Err(Error::InsufficientFees);
+            }
+            self.accrued_fees
+                .insert(currency, &accrued.saturating_sub(amount));
+            Ok(())
+        }
+
+        /// Internal function computing the percentage admin fee on `amount`
+        ///
+        /// Games with an entry fee take no fee from the pool.
+        fn pool_fee(&self, amount: Balance) -> Balance {
+            if self.entry_fee > 0 {
+                return 0;
+            }
+            amount
+                .saturating_mul(self.admin_fee_percentage as Balance)
+                .checked_div(100)
+                .unwrap_or(0)
+        }
+
+        /// Internal function crediting referrers their share of each player's admin fee slice
+        ///
+        /// Slices are proportional to what each player paid in, the fee on sponsor money and the
+        /// overlay is no player's slice; returns the admin cut left.
+        fn pay_referral_shares(&mut self, admin_cut: Balance) -> Balance {
+            if self.referral_percentage == 0 || admin_cut == 0 {
+                return admin_cut;
+            }
+
+            let paid: Vec<(Option<H160>, Balance)> = (0..self.roster_len)
+                .filter_map(|index| self.roster.get((self.current_round, index)))
+                .filter_map(|player| self.get_player_info(player))
+                .map(|info| (info.referrer, info.paid))
+                .collect();
+            let unowned = if self.entry_fee > 0 {
+                0
+            } else {
+                self.sponsored_amount.saturating_add(self.overlay)
+            };
+            let total_paid = paid
+                .iter()
+                .fold(unowned, |total, (_, amount)| total.saturating_add(*amount));
+
+            let mut remaining = admin_cut;
+            for (referrer, amount) in paid {
+                let slice = admin_cut
+                    .saturating_mul(amount)
+                    .checked_div(total_paid)
+                    .unwrap_or(0);
+                remaining = remaining.saturating_sub(self.pay_referral_share(referrer, slice));
+            }
+            remaining
+        }
+
+        /// Internal function crediting a seat's referrer their share of `slice`
+        fn pay_referral_share(&mut self, referrer: Option<H160>, slice: Balance) -> Balance {
+            let referrer = match referrer {
+                Some(referrer) => referrer,
+                None => return 0,
+            };
+            let share = slice
+                .saturating_mul(self.referral_percentage as Balance)
+                .checked_div(100)
+                .unwrap_or(0);
+            if share > 0 {
+                self.credit_in_game_currency(referrer, share);
+                self.referral_earnings.insert(
+                    referrer,
+                    &self.get_referral_earnings(referrer).saturating_add(share),
+                );
+            }
+            share
+        }
+
+        /// Internal function checking the call carries the payment for `amount`
+        ///
+        /// Token games take no native value, the tokens are pulled by `collect_payment`.
+        fn check_payment(&self, amount: Balance) -> Result<()> {
+            let expected = match self.currency {
+                Currency::Native => amount,
+                Currency::Token(_) => 0,
+            };
+            if self.env().transferred_value() != expected.into() {
+                return Err(Error::IncorrectBuyInAmount);
+            }
+            Ok(())
+        }
+
+        /// Internal function pulling `amount` of the game's token from `from`
+        ///
+        /// The token error is decoded as its variant index, see `Currency::Token`.
+        fn collect_payment(&mut self, from: H160, amount: Balance) -> Result<()> {
+            let token = match self.currency {
+                Currency::Native => return Ok(()),
+                Currency::Token(token) => token,
+            };
+            let result = build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(TOKEN_TRANSFER_FROM_SELECTOR))
+                        .push_arg(from)
+                        .push_arg(self.env().address())
+                        .push_arg(amount),
+                )
+                .returns::<core::result::Result<(), u8>>()
+                .try_invoke();
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                _ => Err(Error::TokenTransferFailed),
+            }
+        }
+
+        /// Internal function sending `amount` in the game's currency
+        fn send(&mut self, to: H160, amount: Balance) -> Result<()> {
+            match self.currency {
+                Currency::Native => self
+                    .env()
+                    .transfer(to, amount.into())
+                    .map_err(|_| Error::TransferFailed),
+                Currency::Token(token) => self.token_transfer(token, to, amount),
+            }
+        }
+
+        /// Internal function transferring tokens held by the contract
+        fn token_transfer(&mut self, token: H160, to: H160, amount: Balance) -> Result<()> {
+            let result = build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(TOKEN_TRANSFER_SELECTOR))
+                        .push_arg(to)
+                        .push_arg(amount),
+                )
+                .returns::<core::result::Result<(), u8>>()
+                .try_invoke();
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                _ => Err(Error::TokenTransferFailed),
+            }
+        }
+
+        /// Internal function crediting a claimable balance in the game's currency
+        fn credit_in_game_currency(&mut self, account: H160, amount: Balance) {
+            match self.currency {
+                Currency::Native => self.credit(account, amount),
+                Currency::Token(token) => {
+                    if amount > 0 {
+                        let balance = self
+                            .get_token_claimable(token, account)
+                            .saturating_add(amount);
+                        self.token_claimable.insert((token, account), &balance);
+                    }
+                }
+            }
+        }
+
+        /// Internal function to collect the admin fee
+        ///
+        /// Fees accrue in the contract for a treasurer to withdraw, or with a fee split each
+        /// beneficiary is credited its share instead.
+        fn collect_admin_fee(&mut self, admin_cut: Balance) -> Result<()> {
+            if admin_cut == 0 {
+                return Ok(());
+            }
+            let currency = self.currency;
+            self.lifetime_fees.insert(
+                currency,
+                &self.get_lifetime_fees(currency).saturating_add(admin_cut),
+            );
+            let split = match self.round_fee_split {
+                Some(split) => split,
+                None => {
+                    self.accrued_fees.insert(
+                        currency,
+                        &self.get_accrued_fees(currency).saturating_add(admin_cut),
+                    );
+                    return Ok(());
+                }
+            };
+
+            let share = |bps: u16| admin_cut.saturating_mul(bps as Balance) / 10_000;
+            let host_share = share(split.host_bps);
+            let dev_fund_share = share(split.dev_fund_bps);
+            let charity_share = share(split.charity_bps);
+            self.credit_in_game_currency(split.host, host_share);
+            self.credit_in_game_currency(split.dev_fund, dev_fund_share);
+            if let Some(charity) = split.charity {
+                self.credit_in_game_currency(charity, charity_share);
+            }
+            let treasury_share = admin_cut
+                .saturating_sub(host_share)
+                .saturating_sub(dev_fund_share)
+                .saturating_sub(charity_share);
+            self.credit_in_game_currency(split.treasury, treasury_share);
+            Ok(())
+        }
+
+        /// Internal function to split a buy-in between prize pool and bounty, returns the bounty
+        fn add_buy_in(&mut self, amount: Balance) -> Balance {
+            let bounty = amount
+                .saturating_mul(self.bounty_percentage as Balance)
+                .checked_div(100)
+                .unwrap_or(0);
+            self.bounty_pool = self.bounty_pool.saturating_add(bounty);
+            self.prize_pool = self
+                .prize_pool
+                .saturating_add(amount.saturating_sub(bounty));
+            bounty
+        }
+
+        /// Internal function to add to an account's claimable balance
+        fn credit(&mut self, account: H160, amount: Balance) {
+            if amount > 0 {
+                let balance = self.get_claimable(account).saturating_add(amount);
+                self.claimable.insert(account, &balance);
+            }
+        }
+
+        /// Internal function to append a player to a tournament stage
+        fn add_tournament_entrant(&mut self, id: u32, stage: u32, player: H160) {
+            let size = self.tournament_stage_sizes.get((id, stage)).unwrap_or(0);
+            self.tournament_entrants.insert((id, stage, size), &player);
+            self.tournament_stage_sizes
+                .insert((id, stage), &size.saturating_add(1));
+        }
+
+        /// Internal function to settle a tournament table from its placements (Admin only)
+        ///
+        /// The final table pays the tournament prize structure, other tables advance their
+        /// top placements to the next stage.
+        fn settle_tournament_table(&mut self, placements: Vec<H160>) -> Result<()> {
+            // Check admin access
+            if self.env().caller() != self.game_admin {
+                return Err(Error::NotAdmin);
+            }
+
+            // Check state
+            if self.game_state != GameState::WaitingForResults {
+                return Err(Error::GameNotInCorrectState);
+            }
+
+            // Validate input
+            if placements.is_empty() {
+                return Err(Error::NoWinners);
+            }
+            self.check_placements(&placements)?;
+
+            let mut tournament = self.tournament.clone().ok_or(Error::NoTournament)?;
+
+            if tournament.final_table {
+                // Pay the tournament prize structure out of the tournament pool
+                self.prize_pool = tournament.pool;
+                let (admin_cut, winner_pool) = self.split_admin_cut();
+                for (winner, percentage) in placements.iter().zip(tournament.prize_structure.iter())
+                {
+                    let prize = winner_pool
+                        .saturating_mul(*percentage as Balance)
+                        .checked_div(100)
+                        .unwrap_or(0);
+                    self.pay_prize(*winner, prize)?;
+                }
+                self.record_win(placements[0]);
+                self.collect_admin_fee(admin_cut)?;
+
+                // The tournament field is everyone who registered
+                let field_size = self.get_tournament_stage_size(1);
+                self.trigger_jackpot(&placements[..1], field_size);
+                self.burn_ticket_range(tournament.tickets);
+
+                self.tournament = None;
+                self.reset_game_state();
+                return Ok(());
+            }
+
+            // Advance the top placements
+            for player in placements
+                .iter()
+                .take(tournament.advance_per_table as usize)
+            {
+                self.add_tournament_entrant(
+                    tournament.id,
+                    tournament.stage.saturating_add(1),
+                    *player,
+                );
+            }
+
+            // Move to the next stage once every entrant has played
+            tournament.table_running = false;
+            if tournament.seated >= self.get_tournament_stage_size(tournament.stage) {
+                tournament.stage = tournament.stage.saturating_add(1);
+                tournament.seated = 0;
+            }
+            self.tournament = Some(tournament);
+            self.reset_game_state();
+
+            Ok(())
+        }
+
+        /// Internal function forming and beginning a game from a queue, returns whether it did
+        fn try_match_queue(&mut self, stake: Balance) -> Result<bool> {
+            // The arena must be free
+            if self.game_state != GameState::Inactive || self.tournament.is_some() {
+                return Ok(false);
+            }
+            let tier = match self.queue_tiers.get(stake) {
+                Some(tier) => tier,
+                None => return Ok(false),
+            };
+
+            // Collect waiting players in queue order, looking at most QUEUE_SCAN_LIMIT entries deep
+            let (head, tail) = self.drop_stale_queue_head(stake);
+            let mut waiting: Vec<(H160, u32)> = Vec::new();
+            for position in head..tail.min(head.saturating_add(QUEUE_SCAN_LIMIT)) {
+                let player = match self.queue_entries.get((stake, position)) {
+                    Some(player) => player,
+                    None => continue,
+                };
+                // Skip entries of players who left (or left and joined again)
+                if self.queued.get(player) != Some((stake, position)) {
+                    continue;
+                }
+                waiting.push((player, self.get_rating(player)));
+            }
+
+            // Each waiting player anchors a rating window in turn, longest waiting first
+            let seats = waiting.iter().find_map(|(_, anchor)| {
+                let seats: Vec<H160> = waiting
+                    .iter()
+                    .filter(|(_, rating)| {
+                        tier.rating_width
+                            .is_none_or(|width| rating.abs_diff(*anchor) <= width)
+                    })
+                    .map(|(player, _)| *player)
+                    .take(tier.players_per_game as usize)
+                    .collect();
+                (seats.len() as u32 >= tier.players_per_game).then_some(seats)
+            });
+            let seats = match seats {
+                Some(seats) => seats,
+                None => return Ok(false),
+            };
+
+            // Create the game and seat the matched players
+            self.setup_game(GameConfig {
+                buy_in: stake,
+                min_players: tier.players_per_game,
+                max_players: Some(tier.players_per_game),
+                game_duration: tier.game_duration,
+                ..GameConfig::default()
+            })?;
+            for player in seats.iter() {
+                self.queued.remove(*player);
+                self.seat_player(*player, None, stake, 0, false, None);
+            }
+            self.queue_sizes.insert(
+                stake,
+                &self
+                    .get_queue_size(stake)
+                    .saturating_sub(tier.players_per_game),
+            );
+
+            // Drop consumed entries from the head of the queue
+            self.drop_stale_queue_head(stake);
+
+            let now = self.now();
+            self.begin_game(now);
+
+            Ok(true)
+        }
+
+        /// Internal function dropping entries of players no longer waiting from the head of a
+        /// queue, returns the new bounds
+        fn drop_stale_queue_head(&mut self, stake: Balance) -> (u32, u32) {
+            let (mut head, tail) = self.queue_bounds.get(stake).unwrap_or((0, 0));
+            while head < tail {
+                match self.queue_entries.get((stake, head)) {
+                    Some(player) if self.queued.get(player) == Some((stake, head)) => break,
+                    _ => {
+                        self.queue_entries.remove((stake, head));
+                        head = head.saturating_add(1);
+                    }
+                }
+            }
+            self.queue_bounds.insert(stake, &(head, tail));
+            (head, tail)
+        }
+
+        /// Internal function to check player and team minimums for starting the game
+        fn has_enough_players(&self) -> bool {
+            if self.player_count < self.min_players {
+                return false;
+            }
+            match self.team_config {
+                Some(config) => (0..config.team_count)
+                    .all(|team| self.get_team_size(team) >= config.min_team_size),
+                None => true,
+            }
+        }
+
+        /// Internal function to check the caller holds a role
+        fn ensure_role(&self, role: Role) -> Result<()> {
+            if !self.has_role(role, self.env().caller()) {
+                return Err(Error::MissingRole);
+            }
+            Ok(())
+        }
+
+        /// Current point in time, measured in the configured time basis
+        fn now(&self) -> Timestamp {
+            match self.time_basis {
+                TimeBasis::Timestamp => self.env().block_timestamp(),
+                TimeBasis::Blocks => self.env().block_number() as Timestamp,
+            }
+        }
+
+        /// Internal function to move a game whose registration closed into InProgress
+        ///
+        /// Tournament registrations instead move their entrants into the first bracket stage.
+        fn begin_game(&mut self, now: Timestamp) {
+            // Top the pool up to the guarantee out of the reserve
+            if let Some(guarantee) = self.guaranteed_pool {
+                let current = self.prize_pool.saturating_add(self.bounty_pool);
+                let overlay = guarantee
+                    .saturating_sub(current)
+                    .min(self.guarantee_reserve);
+                self.guarantee_reserve = self.guarantee_reserve.saturating_sub(overlay);
+                self.prize_pool = self.prize_pool.saturating_add(overlay);
+                self.overlay = overlay;
+                self.total_overlay = self.total_overlay.saturating_add(overlay);
+            }
+
+            if let Some(mut tournament) = self.tournament.clone() {
+                if tournament.stage == 0 {
+                    // Registration closed, the pool is held until the final table
+                    tournament.pool = self.prize_pool.saturating_add(self.bounty_pool);
+                    tournament.stage = 1;
+                    tournament.tickets.1 = self.next_ticket_id;
+                    tournament.fee_split = self.round_fee_split;
+                    tournament.overlay = self.overlay;
+
+                    // Record who gets each stake back should the tournament be cancelled
+                    for index in 0..self.roster_len {
+                        let player = match self.roster.get((self.current_round, index)) {
+                            Some(player) => player,
+                            None => continue,
+                        };
+                        let info = match self.get_player_info(player) {
+                            Some(info) => info,
+                            None => continue,
+                        };
+                        for (account, amount) in self.seat_refunds(player, &info) {
+                            if amount > 0 {
+                                self.tournament_refunds.insert(
+                                    (tournament.id, tournament.refund_count),
+                                    &(account, amount),
+                                );
+                                tournament.refund_count = tournament.refund_count.saturating_add(1);
+                            }
+                        }
+                    }
+                    self.reset_game_state();
+                    self.tournament = Some(tournament);
+                    return;
+                }
+            }
+
+            self.game_state = GameState::InProgress;
+            self.game_start_time = now;
+            self.alive_count = self.player_count;
+
+            // Emit GameBegan event (commented for MVP due to ink! v6 compatibility)
+            // self.env().emit_event(GameBegan {
+            //     player_count: self.player_count,
+            //     game_start_time: self.game_start_time,
+            // });
+        }
+
+        /// Internal function to end the game and open the settlement window
+        fn enter_waiting_for_results(&mut self, now: Timestamp) {
+            self.game_state = GameState::WaitingForResults;
+            if let Some(period) = self.settlement_period {
+                self.settlement_deadline = now.saturating_add(period);
+            }
+        }
+
+        /// Internal function to reset the game state
+        fn reset_game_state(&mut self) {
+            // Seats of the finished round are no longer held, tournament entries are held
+            // until the tournament ends
+            let registration_closing = self.tournament.as_ref().is_some_and(|t| t.stage == 0);
+            if !registration_closing {
+                for index in 0..self.roster_len {
+                    let ticket = self
+                        .roster
+                        .get((self.current_round, index))
+                        .and_then(|player| self.get_player_info(player))
+                        .and_then(|info| info.ticket);
+                    if let Some(ticket) = ticket {
+                        self.burn_ticket(ticket);
+                    }
+                }
+            }
+
+            self.game_state = GameState::Inactive;
+            self.buy_in_amount = 0;
+            self.registration_deadline = 0;
+            self.min_players = 0;
+            self.max_players = None;
+            self.game_duration = None;
+            self.game_start_time = 0;
+            self.settlement_period = None;
+            self.settlement_deadline = 0;
+            self.time_basis = TimeBasis::Timestamp;
+            self.late_registration_period = None;
+            self.late_buy_in_amount = None;
+            self.rebuy_config = None;
+            self.rebuy_count = 0;
+            self.cash_game = false;
+            self.bounty_percentage = 0;
+            self.bounty_pool = 0;
+            self.alive_count = 0;
+            self.elimination_count = 0;
+            self.team_config = None;
+            self.rating_band = None;
+            self.entry_gate = EntryGate::Open;
+            self.currency = Currency::Native;
+            self.pegged_buy_in = None;
+            self.entry_fee = 0;
+            self.keep_entry_fee_on_refund = false;
+            self.entry_fees = 0;
+            self.round_fee_split = None;
+            self.sponsor_count = 0;
+            self.sponsored_amount = 0;
+            self.guaranteed_pool = None;
+            self.overlay = 0;
+            // Unused guarantee reserve goes back to the treasury
+            self.treasury_balance = self.treasury_balance.saturating_add(self.guarantee_reserve);
+            self.guarantee_reserve = 0;
+            self.player_count = 0;
+            self.prize_pool = 0;
+            // Note: The players mapping is not cleared since clearing mappings is expensive.
+            // Records are tagged with their round and ignored once a new game starts.
+        }
+    }
+
+    // Events temporarily removed for MVP compilation - will add back later
+
+    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
+    /// module and test functions are marked with a `#[test]` attribute.
+    /// The below code is technically just normal Rust code.
+    #[cfg(test)]
+    mod tests {
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+
+        /// Set the value transferred with the next call
+        fn set_value(amount: Balance) {
+            ink::env::test::set_value_transferred(amount.into());
+        }
+
+        /// Deposit `amount` into the game on behalf of `player`
+        fn deposit_as(contract: &mut AgarioBuyin, player: H160, amount: Balance) -> Result<()> {
+            ink::env::test::set_caller(player);
+            set_value(amount);
+            contract.deposit()
+        }
+
+        /// Advance the test chain by `blocks` blocks
+        fn advance_blocks(blocks: u32) {
+            for _ in 0..blocks {
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+        }
+
+        /// Seat `players` in the current round without taking a deposit
+        fn seat_players(contract: &mut AgarioBuyin, players: &[H160]) {
+            let info = PlayerInfo {
+                round: contract.get_current_round(),
+                entries: 1,
+                ..Default::default()
+            };
+            for player in players {
+                contract.players.insert(*player, &info);
+            }
+        }
+
+        /// We test if the default constructor does its job.
+        #[ink::test]
+        fn constructor_works() {
+            let contract = AgarioBuyin::new(5).unwrap();
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
+        }
+
+        /// We test constructor validation for admin fee.
+        #[ink::test]
+        fn constructor_validates_admin_fee() {
+            // Valid admin fee should work
+            let result = AgarioBuyin::new(50);
+            assert!(matches!(result, Ok(_)));
+
+            // Invalid admin fee should fail
+            let result = AgarioBuyin::new(101);
+            assert!(matches!(result, Err(Error::InvalidAdminFee)));
+        }
+
+        /// Test new query functions work correctly.
+        #[ink::test]
+        fn enhanced_query_functions_work() {
+            let contract = AgarioBuyin::new(5).unwrap();
+
+            // Test initial values
+            assert_eq!(contract.get_player_count(), 0);
+            assert_eq!(contract.get_prize_pool(), 0);
+            assert_eq!(contract.get_min_players(), 0);
+            assert_eq!(contract.get_game_duration(), None);
+            assert_eq!(contract.get_buy_in_amount(), 0);
+
+            // Test admin getter
+            assert_eq!(contract.get_admin(), contract.game_admin);
+        }
+
+        /// Test player registration checking.
+        #[ink::test]
+        fn player_registration_check_works() {
+            let contract = AgarioBuyin::new(5).unwrap();
+
+            // Create a mock H160 address
+            let player_address = H160::from([1; 20]);
+
+            // Player should not be registered initially
+            assert!(!contract.is_player_registered(player_address));
+
+            // Test with different address
+            let other_address = H160::from([2; 20]);
+            assert!(!contract.is_player_registered(other_address));
+        }
+
+        /// Test time remaining functions.
+        #[ink::test]
+        fn time_remaining_functions_work() {
+            let contract = AgarioBuyin::new(5).unwrap();
+
+            // Should return 0 when game is inactive
+            assert_eq!(contract.get_registration_time_remaining(), 0);
+            assert_eq!(contract.get_game_time_remaining(), None);
+        }
+
+        /// Test start_game function works correctly.
+        #[ink::test]
+        fn start_game_works() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Should work with valid parameters
+            let result = contract.start_game(1000, 5, 2, Some(10));
+            assert!(matches!(result, Ok(())));
+
+            // Check state changed
+            assert_eq!(contract.get_game_state(), GameState::AcceptingDeposits);
+            assert_eq!(contract.get_buy_in_amount(), 1000);
+            assert_eq!(contract.get_min_players(), 2);
+        }
+
+        /// Test start_game validation.
+        #[ink::test]
+        fn start_game_validates_parameters() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Should fail with too few players
+            let result = contract.start_game(1000, 5, 1, Some(10));
+            assert!(matches!(result, Err(Error::TooFewPlayers)));
+
+            // Start a valid game
+            contract.start_game(1000, 5, 2, Some(10)).unwrap();
+
+            // Should fail if game already started
+            let result = contract.start_game(2000, 5, 2, Some(10));
+            assert!(matches!(result, Err(Error::GameNotInCorrectState)));
+        }
+
+        /// Test deposit function works correctly.
+        #[ink::test]
+        fn deposit_works() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Start a game first
+            contract.start_game(1000, 5, 2, Some(10)).unwrap();
+
+            // Mock the deposit by setting transferred value
+            // Note: In actual tests, this would be handled by the test environment
+            // For unit tests, we'll test the logic validation indirectly
+        }
+
+        /// Test game flow transitions.
+        #[ink::test]
+        fn game_flow_transitions_work() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Start with inactive state
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
+
+            // Start game
+            contract.start_game(1000, 5, 2, Some(10)).unwrap();
+            assert_eq!(contract.get_game_state(), GameState::AcceptingDeposits);
+
+            // Test try_begin_game with insufficient time passed
+            let result = contract.try_begin_game();
+            assert!(matches!(result, Ok(())));
+            // Should still be accepting deposits if deadline not passed
+        }
+
+        /// Test admin functions require admin access.
+        #[ink::test]
+        fn admin_functions_require_admin() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // These functions should only work for admin
+            // In actual implementation, you'd mock a different caller
+
+            // start_game should work for admin (constructor caller)
+            let result = contract.start_game(1000, 5, 2, Some(10));
+            assert!(matches!(result, Ok(())));
+        }
+
+        /// Test winner submission validation.
+        #[ink::test]
+        fn submit_winners_validates_input() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Start game and move to WaitingForResults state
+            contract.start_game(1000, 5, 2, Some(10)).unwrap();
+            contract.game_state = GameState::WaitingForResults;
+            contract.prize_pool = 1000;
+
+            // Test empty winners
+            let result = contract.submit_winners(vec![], vec![], GameEndReason::TimeLimit);
+            assert!(matches!(result, Err(Error::NoWinners)));
+
+            // Test mismatched vectors
+            let winners = vec![H160::from([1; 20])];
+            let percentages = vec![50, 30]; // Different length
+            let result = contract.submit_winners(winners, percentages, GameEndReason::TimeLimit);
+            assert!(matches!(result, Err(Error::MismatchedData)));
+
+            // Test invalid percentages
+            let winners = vec![H160::from([1; 20]), H160::from([2; 20])];
+            let percentages = vec![60, 50]; // Total > 100
+            let result = contract.submit_winners(winners, percentages, GameEndReason::TimeLimit);
+            assert!(matches!(result, Err(Error::InvalidPercentages)));
+
+            // Test winners without a seat or placed twice
+            let winners = vec![H160::from([1; 20]), H160::from([1; 20])];
+            let result =
+                contract.submit_winners(winners.clone(), vec![50, 50], GameEndReason::TimeLimit);
+            assert!(matches!(result, Err(Error::PlayerNotRegistered)));
+            seat_players(&mut contract, &winners[..1]);
+            let result = contract.submit_winners(winners, vec![50, 50], GameEndReason::TimeLimit);
+            assert!(matches!(result, Err(Error::DuplicatePlacement)));
+            assert_eq!(contract.get_claimable(H160::from([1; 20])), 0);
+        }
+
+        /// Test check_game_conditions function for automatic state transitions
+        #[ink::test]
+        fn check_game_conditions_handles_transitions() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Test 1: Transition from AcceptingDeposits to InProgress
+            // Use a past timestamp so the deadline is definitely passed
+            contract.start_game(1000, 0, 2, Some(10)).unwrap(); // registration_deadline = 0 (immediate)
+            contract.player_count = 3; // Enough players
+
+            // Force the registration deadline to be in the past by setting it manually
+            contract.registration_deadline = 0; // Definitely in the past
+
+            // Should transition to InProgress
+            let result = contract.check_game_conditions();
+            assert!(matches!(result, Ok(())));
+            assert_eq!(contract.get_game_state(), GameState::InProgress);
+            // Note: game_start_time will be set to current block timestamp (likely 0 in test env)
+
+            // Test 2: Game duration expiry transition
+            // Set up a scenario where game duration has expired
+            contract.game_state = GameState::InProgress;
+            contract.game_start_time = 0; // Game started at time 0
+            contract.game_duration = Some(0); // Duration is 0, so immediately expired
+
+            // Should transition to WaitingForResults
+            let result = contract.check_game_conditions();
+            assert!(matches!(result, Ok(())));
+            assert_eq!(contract.get_game_state(), GameState::WaitingForResults);
+        }
+
+        /// Test check_game_conditions refund logic
+        #[ink::test]
+        fn check_game_conditions_refunds_on_insufficient_players() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Start game with immediate deadline but insufficient players
+            contract.start_game(1000, 0, 5, Some(10)).unwrap(); // Need 5 players
+            contract.player_count = 2; // Only 2 players
+
+            // Should refund and reset to Inactive
+            let result = contract.check_game_conditions();
+            assert!(matches!(result, Ok(())));
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
+            assert_eq!(contract.player_count, 0);
+        }
+
+        /// Test check_game_conditions no-op for other states
+        #[ink::test]
+        fn check_game_conditions_no_op_for_other_states() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Test Inactive state
+            let result = contract.check_game_conditions();
+            assert!(matches!(result, Ok(())));
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
+
+            // Test WaitingForResults state
+            contract.game_state = GameState::WaitingForResults;
+            let result = contract.check_game_conditions();
+            assert!(matches!(result, Ok(())));
+            assert_eq!(contract.get_game_state(), GameState::WaitingForResults);
+        }
+
+        /// Test reset game state function.
+        #[ink::test]
+        fn reset_game_state_works() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Set up some state
+            contract.start_game(1000, 5, 2, Some(10)).unwrap();
+            assert_eq!(contract.get_game_state(), GameState::AcceptingDeposits);
+
+            // Reset state
+            contract.reset_game_state();
+
+            // Check everything is reset
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
+            assert_eq!(contract.get_buy_in_amount(), 0);
+            assert_eq!(contract.get_player_count(), 0);
+            assert_eq!(contract.get_prize_pool(), 0);
+        }
+
+        #[ink::test]
+        fn submit_winners_distributes_prizes_correctly() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Setup game with multiple players
+            let _ = contract.start_game(1000, 5, 2, Some(60));
+            contract.game_state = GameState::WaitingForResults;
+            contract.prize_pool = 10000; // 10 DOT total prize pool
+            contract.player_count = 4;
+
+            // Define winners: 1st place 50%, 2nd place 30%, 3rd place 20%
+            let winners = vec![
+                H160::from([1; 20]), // 1st place
+                H160::from([2; 20]), // 2nd place
+                H160::from([3; 20]), // 3rd place
+            ];
+            let percentages = vec![50, 30, 20]; // Total 100%
+            seat_players(&mut contract, &winners);
+
+            // Submit winners
+            let result = contract.submit_winners(
+                winners.clone(),
+                percentages.clone(),
+                GameEndReason::TimeLimit,
+            );
+            assert!(result.is_ok());
+
+            // Verify game state reset
+            assert_eq!(contract.game_state, GameState::Inactive);
+            assert_eq!(contract.prize_pool, 0);
+            assert_eq!(contract.player_count, 0);
+
+            // Calculate expected distributions (5% admin fee)
+            let admin_fee = 10000 * 5 / 100; // 500
+            let winner_pool = 10000 - admin_fee; // 9500
+            let first_prize = winner_pool * 50 / 100; // 4750
+            let second_prize = winner_pool * 30 / 100; // 2850
+            let third_prize = winner_pool * 20 / 100; // 1900
+
+            // Note: In a real test environment, we'd verify the actual transfers
+            // For now, we verify the function completed successfully
+        }
+
+        #[ink::test]
+        fn submit_winners_handles_partial_percentages() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Setup game
+            let _ = contract.start_game(1000, 5, 2, Some(60));
+            contract.game_state = GameState::WaitingForResults;
+            contract.prize_pool = 10000;
+
+            // Only distribute 80% of winnings, 20% stays in contract
+            let winners = vec![H160::from([1; 20]), H160::from([2; 20])];
+            let percentages = vec![50, 30]; // Total 80%
+            seat_players(&mut contract, &winners);
+
+            let result =
+                contract.submit_winners(winners, percentages, GameEndReason::LastPlayerStanding);
+            assert!(result.is_ok());
+            assert_eq!(contract.game_state, GameState::Inactive);
+        }
+
+        #[ink::test]
+        fn submit_winners_enforces_admin_only() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Setup game in WaitingForResults state
+            contract.game_state = GameState::WaitingForResults;
+            contract.prize_pool = 10000;
+
+            // Change caller to non-admin (default test caller is admin)
+            ink::env::test::set_caller(H160::from([99; 20]));
+
+            let winners = vec![H160::from([1; 20])];
+            let percentages = vec![100];
+
+            let result = contract.submit_winners(winners, percentages, GameEndReason::TimeLimit);
+            assert!(matches!(result, Err(Error::NotAdmin)));
+        }
+
+        #[ink::test]
+        fn submit_winners_requires_correct_game_state() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Test various wrong states
+            let winners = vec![H160::from([1; 20])];
+            let percentages = vec![100];
+
+            // Test Inactive state
+            contract.game_state = GameState::Inactive;
+            let result = contract.submit_winners(
+                winners.clone(),
+                percentages.clone(),
+                GameEndReason::TimeLimit,
+            );
+            assert!(matches!(result, Err(Error::GameNotInCorrectState)));
+
+            // Test AcceptingDeposits state
+            contract.game_state = GameState::AcceptingDeposits;
+            let result = contract.submit_winners(
+                winners.clone(),
+                percentages.clone(),
+                GameEndReason::TimeLimit,
+            );
+            assert!(matches!(result, Err(Error::GameNotInCorrectState)));
+
+            // Test InProgress state
+            contract.game_state = GameState::InProgress;
+            let result = contract.submit_winners(
+                winners.clone(),
+                percentages.clone(),
+                GameEndReason::TimeLimit,
+            );
+            assert!(matches!(result, Err(Error::GameNotInCorrectState)));
+
+            // Only WaitingForResults should work
+            contract.game_state = GameState::WaitingForResults;
+            contract.prize_pool = 1000;
+            seat_players(&mut contract, &winners);
+            let result = contract.submit_winners(winners, percentages, GameEndReason::TimeLimit);
+            assert!(result.is_ok());
+        }
+
+        /// 🎯 MVP CRITICAL TEST: Complete happy path flow (start → deposit → end)
+        /// This test simulates the exact flow that will be demonstrated in the hackathon
+        #[ink::test]
+        fn mvp_complete_happy_path_flow() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // STEP 1: Admin starts game (Demo Step 1)
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
+            let result = contract.start_game(10000, 10, 2, Some(30)); // 10,000 units buy-in, 10min reg, 2 min players, 30min duration
+            assert!(result.is_ok());
+            assert_eq!(contract.get_game_state(), GameState::AcceptingDeposits);
+            assert_eq!(contract.get_buy_in_amount(), 10000);
+            assert_eq!(contract.get_min_players(), 2);
+
+            // STEP 2: Players join game (Demo Step 2)
+            // Simulate multiple players depositing
+            // Note: In real demo, we'd use payable calls with actual DOT transfers
+
+            // Manually simulate player registration for MVP testing
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let player3 = H160::from([3; 20]);
+
+            // Simulate player deposits by manually updating state (for unit test)
+            let player_record = PlayerInfo {
+                round: contract.get_current_round(),
+                late_entry: false,
+                entries: 1,
+                ..Default::default()
+            };
+            contract.players.insert(player1, &player_record);
+            contract.player_count = 1;
+            contract.prize_pool = 10000; // Player 1 deposit
+
+            contract.players.insert(player2, &player_record);
+            contract.player_count = 2;
+            contract.prize_pool = 20000; // Player 1 + 2 deposits
+
+            contract.players.insert(player3, &player_record);
+            contract.player_count = 3;
+            contract.prize_pool = 30000; // Player 1 + 2 + 3 deposits
+
+            // Verify player registrations
+            assert!(contract.is_player_registered(player1));
+            assert!(contract.is_player_registered(player2));
+            assert!(contract.is_player_registered(player3));
+            assert_eq!(contract.get_player_count(), 3);
+            assert_eq!(contract.get_prize_pool(), 30000);
+
+            // STEP 3: Game begins automatically when conditions met
+            // Simulate time passing and try to begin game
+            contract.registration_deadline = 0; // Simulate deadline passed
+            let result = contract.try_begin_game();
+            assert!(result.is_ok());
+            assert_eq!(contract.get_game_state(), GameState::InProgress);
+
+            // STEP 4: Game ends and transitions to WaitingForResults
+            let result = contract.report_game_end(GameEndReason::LastPlayerStanding);
+            assert!(result.is_ok());
+            assert_eq!(contract.get_game_state(), GameState::WaitingForResults);
+
+            // STEP 5: Admin submits winners and distributes prizes (Demo Step 3)
+            let winners = vec![player1, player2]; // 1st and 2nd place
+            let percentages = vec![60, 40]; // 60% to winner, 40% to second place
+
+            let result = contract.submit_winners(
+                winners.clone(),
+                percentages.clone(),
+                GameEndReason::LastPlayerStanding,
+            );
+            assert!(result.is_ok());
+
+            // STEP 6: Verify game reset for next round (Demo Step 4)
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
+            assert_eq!(contract.get_player_count(), 0);
+            assert_eq!(contract.get_prize_pool(), 0);
+            assert_eq!(contract.get_buy_in_amount(), 0);
+
+            // Prize distribution verification:
+            // Total pool: 30,000 units
+            // Admin fee (5%): 1,500 units
+            // Winner pool: 28,500 units
+            // 1st place (60%): 17,100 units
+            // 2nd place (40%): 11,400 units
+            // Note: Actual transfer verification would be done in E2E tests
+        }
+
+        /// 🎯 MVP TEST: Admin access control enforcement
+        #[ink::test]
+        fn mvp_admin_access_control_comprehensive() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Mock non-admin caller
+            let non_admin_address = H160::from([99; 20]);
+
+            // Test 1: start_game requires admin
+            ink::env::test::set_caller(non_admin_address);
+            let result = contract.start_game(1000, 10, 2, Some(30));
+            assert!(matches!(result, Err(Error::NotAdmin)));
+
+            // Reset to admin caller
+            ink::env::test::set_caller(contract.game_admin);
+            contract.start_game(1000, 10, 2, Some(30)).unwrap();
+            contract.game_state = GameState::WaitingForResults;
+            contract.prize_pool = 1000;
+
+            // Test 2: submit_winners requires admin
+            ink::env::test::set_caller(non_admin_address);
+            let winners = vec![H160::from([1; 20])];
+            let percentages = vec![100];
+            let result = contract.submit_winners(winners, percentages, GameEndReason::TimeLimit);
+            assert!(matches!(result, Err(Error::NotAdmin)));
+
+            // Test 3: force_end_game requires admin
+            let result = contract.force_end_game();
+            assert!(matches!(result, Err(Error::NotAdmin)));
+
+            // Reset to admin and verify functions work
+            ink::env::test::set_caller(contract.game_admin);
+            let winners = vec![H160::from([1; 20])];
+            let percentages = vec![100];
+            seat_players(&mut contract, &winners);
+            let result = contract.submit_winners(winners, percentages, GameEndReason::TimeLimit);
+            assert!(result.is_ok());
+        }
+
+        /// 🎯 MVP TEST: Critical error cases for demo robustness
+        #[ink::test]
+        fn mvp_critical_error_cases() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Error Case 1: Starting game with invalid parameters
+            let result = contract.start_game(1000, 10, 1, Some(30)); // < 2 min players
+            assert!(matches!(result, Err(Error::TooFewPlayers)));
+
+            // Error Case 2: Operations in wrong game state
+            let winners = vec![H160::from([1; 20])];
+            let percentages = vec![100];
+            let result = contract.submit_winners(
+                winners.clone(),
+                percentages.clone(),
+                GameEndReason::TimeLimit,
+            );
+            assert!(matches!(result, Err(Error::GameNotInCorrectState))); // Game not started
+
+            // Error Case 3: Invalid winner data
+            contract.start_game(1000, 10, 2, Some(30)).unwrap();
+            contract.game_state = GameState::WaitingForResults;
+            contract.prize_pool = 1000;
+
+            // Empty winners
+            let result = contract.submit_winners(vec![], vec![], GameEndReason::TimeLimit);
+            assert!(matches!(result, Err(Error::NoWinners)));
+
+            // Mismatched data
+            let result = contract.submit_winners(winners, vec![50, 30], GameEndReason::TimeLimit); // length mismatch
+            assert!(matches!(result, Err(Error::MismatchedData)));
+
+            // Invalid percentages (> 100%)
+            let winners = vec![H160::from([1; 20]), H160::from([2; 20])];
+            let percentages = vec![60, 50]; // 110% total
+            let result = contract.submit_winners(winners, percentages, GameEndReason::TimeLimit);
+            assert!(matches!(result, Err(Error::InvalidPercentages)));
+        }
+
+        /// 🎯 MVP TEST: Game timing and automatic transitions
+        #[ink::test]
+        fn mvp_timing_and_transitions() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            // Test automatic transition from registration to game start
+            contract.start_game(1000, 0, 2, Some(10)).unwrap(); // Immediate deadline
+            contract.player_count = 3; // Sufficient players
+            contract.registration_deadline = 0; // Past deadline
+
+            // Should auto-transition to InProgress
+            let result = contract.check_game_conditions();
+            assert!(result.is_ok());
+            assert_eq!(contract.get_game_state(), GameState::InProgress);
+
+            // Test game duration expiry
+            contract.game_start_time = 0;
+            contract.game_duration = Some(0); // Immediate expiry
+
+            // Should auto-transition to WaitingForResults
+            let result = contract.check_game_conditions();
+            assert!(result.is_ok());
+            assert_eq!(contract.get_game_state(), GameState::WaitingForResults);
 
-            Ok(())
+            // Test refund on insufficient players
+            let mut contract2 = AgarioBuyin::new(5).unwrap();
+            contract2.start_game(1000, 0, 5, Some(10)).unwrap(); // Need 5 players
+            contract2.player_count = 2; // Only 2 players
+            contract2.registration_deadline = 0; // Past deadline
+
+            // Should refund and reset
+            let result = contract2.check_game_conditions();
+            assert!(result.is_ok());
+            assert_eq!(contract2.get_game_state(), GameState::Inactive);
+            assert_eq!(contract2.player_count, 0);
         }
 
-        /// Force end game and refund all players (Admin only, emergency function)
-        #[ink(message)]
-        pub fn force_end_game(&mut self) -> Result<()> {
-            // Check admin access
-            if self.env().caller() != self.game_admin {
-                return Err(Error::NotAdmin);
-            }
+        /// Test block-number based deadlines and time remaining queries
+        #[ink::test]
+        fn block_time_basis_works() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let start_block = ink::env::block_number::<ink::env::DefaultEnvironment>() as u64;
+
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                time_basis: TimeBasis::Blocks,
+                registration_period: 3,
+                game_duration: Some(5),
+                ..Default::default()
+            };
+            contract.start_game_with_config(config).unwrap();
+            assert_eq!(contract.get_time_basis(), TimeBasis::Blocks);
+            assert_eq!(contract.get_registration_deadline(), start_block + 3);
+            assert_eq!(contract.get_game_duration(), Some(5));
+            assert_eq!(contract.get_registration_time_remaining(), 3);
+
+            // Remaining time is counted in blocks
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(contract.get_registration_time_remaining(), 2);
+
+            // Game duration is also counted in blocks
+            contract.player_count = 2;
+            contract.registration_deadline = 0;
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.get_game_state(), GameState::InProgress);
+            assert_eq!(contract.get_game_time_remaining(), Some(5));
+        }
 
-            // Can only force end if game is active
-            if matches!(self.game_state, GameState::Inactive) {
-                return Err(Error::GameNotInCorrectState);
-            }
+        /// Test that an expired settlement window refunds the game
+        #[ink::test]
+        fn settlement_deadline_refunds_game() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
 
-            // Refund all players
-            self.refund_all_players()?;
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                time_basis: TimeBasis::Blocks,
+                registration_period: 1,
+                settlement_period: Some(2),
+                ..Default::default()
+            };
+            contract.start_game_with_config(config).unwrap();
+            deposit_as(&mut contract, player1, 1000).unwrap();
+            deposit_as(&mut contract, player2, 1000).unwrap();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.get_game_state(), GameState::InProgress);
 
-            Ok(())
-        }
+            // Ending the game opens the settlement window
+            contract.report_game_end(GameEndReason::TimeLimit).unwrap();
+            assert_eq!(contract.get_settlement_time_remaining(), Some(2));
 
-        /// Internal function to refund all players
-        fn refund_all_players(&mut self) -> Result<()> {
-            self.refund_all_players_with_reason(GameEndReason::AdminForced)
+            // Still within the window
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.get_game_state(), GameState::WaitingForResults);
+
+            // Window expired, players are refunded
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
+            assert_eq!(contract.get_claimable(player1), 1000);
+            assert_eq!(contract.get_claimable(player2), 1000);
         }
 
-        /// Internal function to refund all players with specific reason
-        fn refund_all_players_with_reason(&mut self, _reason: GameEndReason) -> Result<()> {
-            let _total_refunded = self.prize_pool;
-            let _players_refunded = self.player_count;
+        /// Test late registration during InProgress
+        #[ink::test]
+        fn late_registration_works() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let admin = contract.get_admin();
+
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                time_basis: TimeBasis::Blocks,
+                registration_period: 2,
+                late_registration_period: Some(3),
+                late_buy_in: Some(1500),
+                ..Default::default()
+            };
+            contract.start_game_with_config(config).unwrap();
 
-            if self.player_count > 0 && self.prize_pool > 0 {
-                let _refund_per_player = self
-                    .prize_pool
-                    .checked_div(self.player_count as Balance)
-                    .unwrap_or(0);
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let late_player = H160::from([3; 20]);
+            deposit_as(&mut contract, player1, 1000).unwrap();
+            deposit_as(&mut contract, player2, 1000).unwrap();
+            assert_eq!(
+                contract.get_player_info(player1).map(|p| p.late_entry),
+                Some(false)
+            );
 
-                // Note: In a real implementation, you'd iterate through all players
-                // For MVP, we'll just reset the state and emit event
+            // Registration closes and the game begins
+            advance_blocks(2);
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.get_game_state(), GameState::InProgress);
+            assert_eq!(contract.get_late_registration_time_remaining(), Some(3));
 
-                // Emit GameRefunded event (commented for MVP due to ink! v6 compatibility)
-                // self.env().emit_event(GameRefunded {
-                //     players_refunded: _players_refunded,
-                //     total_refunded: _total_refunded,
-                //     reason: _reason,
-                // });
-            }
+            // Late entrants pay the late buy-in
+            let result = deposit_as(&mut contract, late_player, 1000);
+            assert!(matches!(result, Err(Error::IncorrectBuyInAmount)));
+            deposit_as(&mut contract, late_player, 1500).unwrap();
+            assert_eq!(contract.get_player_count(), 3);
+            assert_eq!(contract.get_prize_pool(), 3500);
+            assert_eq!(
+                contract.get_player_info(late_player).map(|p| p.late_entry),
+                Some(true)
+            );
 
-            // Reset game state
-            self.reset_game_state();
+            // Late window closed
+            advance_blocks(3);
+            let result = deposit_as(&mut contract, H160::from([4; 20]), 1500);
+            assert!(matches!(result, Err(Error::RegistrationClosed)));
 
-            Ok(())
+            // Records from an earlier round do not count in the next one
+            ink::env::test::set_caller(admin);
+            contract.force_end_game().unwrap();
+            contract.start_game(1000, 5, 2, None).unwrap();
+            assert!(!contract.is_player_registered(player1));
         }
 
-        /// Internal function to reset the game state
-        fn reset_game_state(&mut self) {
-            self.game_state = GameState::Inactive;
-            self.buy_in_amount = 0;
-            self.registration_deadline = 0;
-            self.min_players = 0;
-            self.max_players = None;
-            self.game_duration = None;
-            self.game_start_time = 0;
-            self.player_count = 0;
-            self.prize_pool = 0;
-            // Note: In production, you'd also clear the players mapping
-            // For MVP, we'll leave it as-is since clearing mappings is expensive
+        /// Test deposits are rejected during InProgress without a late window
+        #[ink::test]
+        fn deposit_rejected_in_progress_without_late_window() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            contract.start_game(1000, 5, 2, Some(10)).unwrap();
+            contract.game_state = GameState::InProgress;
+
+            let result = deposit_as(&mut contract, H160::from([1; 20]), 1000);
+            assert!(matches!(result, Err(Error::GameNotInCorrectState)));
         }
-    }
 
-    // Events temporarily removed for MVP compilation - will add back later
+        /// Test re-buys are limited per player and per game
+        #[ink::test]
+        fn rebuy_works_within_limits() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
 
-    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
-    /// module and test functions are marked with a `#[test]` attribute.
-    /// The below code is technically just normal Rust code.
-    #[cfg(test)]
-    mod tests {
-        /// Imports all the definitions from the outer scope so we can use them here.
-        use super::*;
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                registration_period: 5,
+                rebuy: Some(RebuyConfig {
+                    amount: 500,
+                    max_per_player: 1,
+                    max_per_game: 2,
+                }),
+                ..Default::default()
+            };
+            contract.start_game_with_config(config).unwrap();
 
-        /// We test if the default constructor does its job.
-        #[ink::test]
-        fn constructor_works() {
-            let contract = AgarioBuyin::new(5).unwrap();
-            assert_eq!(contract.get_game_state(), GameState::Inactive);
-        }
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let player3 = H160::from([3; 20]);
+            for player in [player1, player2, player3] {
+                deposit_as(&mut contract, player, 1000).unwrap();
+            }
 
-        /// We test constructor validation for admin fee.
-        #[ink::test]
-        fn constructor_validates_admin_fee() {
-            // Valid admin fee should work
-            let result = AgarioBuyin::new(50);
-            assert!(matches!(result, Ok(_)));
+            // Re-buys are only valid once the game is running
+            ink::env::test::set_caller(player1);
+            set_value(500);
+            assert!(matches!(
+                contract.rebuy(),
+                Err(Error::GameNotInCorrectState)
+            ));
+            contract.game_state = GameState::InProgress;
 
-            // Invalid admin fee should fail
-            let result = AgarioBuyin::new(101);
-            assert!(matches!(result, Err(Error::InvalidAdminFee)));
+            // Wrong amount
+            set_value(400);
+            assert!(matches!(contract.rebuy(), Err(Error::IncorrectBuyInAmount)));
+
+            // An eliminated player re-buys back into the game
+            let server = H160::from([9; 20]);
+            ink::env::test::set_caller(contract.get_admin());
+            contract.grant_role(Role::GameServer, server).unwrap();
+            contract.alive_count = 3;
+            ink::env::test::set_caller(server);
+            contract
+                .report_eliminations(vec![(player2, player1)])
+                .unwrap();
+            assert_eq!(contract.get_eliminated_player(1), Some(player1));
+
+            // First re-buy succeeds, second hits the per-player limit
+            ink::env::test::set_caller(player1);
+            set_value(500);
+            contract.rebuy().unwrap();
+            assert_eq!(contract.get_player_entries(player1), 2);
+            assert!(contract.is_player_alive(player1));
+            assert_eq!(contract.get_eliminated_player(1), None);
+            assert_eq!(contract.get_elimination_order(player1), None);
+            assert_eq!(contract.get_prize_pool(), 3500);
+            assert!(matches!(contract.rebuy(), Err(Error::RebuyLimitReached)));
+
+            // Second player uses the last re-buy of the game
+            ink::env::test::set_caller(player2);
+            contract.rebuy().unwrap();
+            ink::env::test::set_caller(player3);
+            assert!(matches!(contract.rebuy(), Err(Error::RebuyLimitReached)));
+            assert_eq!(contract.get_rebuy_count(), 2);
+
+            // Unregistered players cannot re-buy
+            ink::env::test::set_caller(H160::from([4; 20]));
+            assert!(matches!(contract.rebuy(), Err(Error::PlayerNotRegistered)));
         }
 
-        /// Test new query functions work correctly.
+        /// Test re-buys are rejected when not configured
         #[ink::test]
-        fn enhanced_query_functions_work() {
-            let contract = AgarioBuyin::new(5).unwrap();
-
-            // Test initial values
-            assert_eq!(contract.get_player_count(), 0);
-            assert_eq!(contract.get_prize_pool(), 0);
-            assert_eq!(contract.get_min_players(), 0);
-            assert_eq!(contract.get_game_duration(), None);
-            assert_eq!(contract.get_buy_in_amount(), 0);
+        fn rebuy_requires_config() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            contract.start_game(1000, 5, 2, None).unwrap();
+            contract.game_state = GameState::InProgress;
 
-            // Test admin getter
-            assert_eq!(contract.get_admin(), contract.game_admin);
+            assert!(matches!(contract.rebuy(), Err(Error::RebuyNotAllowed)));
         }
 
-        /// Test player registration checking.
+        /// Test mid-game cash-out in a cash game
         #[ink::test]
-        fn player_registration_check_works() {
-            let contract = AgarioBuyin::new(5).unwrap();
+        fn cash_out_works_in_cash_game() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let admin = contract.get_admin();
+            let server = H160::from([50; 20]);
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let session_key = H160::from([11; 20]);
+
+            contract.grant_role(Role::GameServer, server).unwrap();
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                registration_period: 5,
+                cash_game: true,
+                ..Default::default()
+            };
+            contract.start_game_with_config(config).unwrap();
+            deposit_as(&mut contract, player1, 1000).unwrap();
+            deposit_as(&mut contract, player2, 1000).unwrap();
+            ink::env::test::set_caller(player1);
+            contract.set_session_key(Some(session_key)).unwrap();
+            contract.game_state = GameState::InProgress;
 
-            // Create a mock H160 address
-            let player_address = H160::from([1; 20]);
+            // Other accounts cannot request a cash-out for the player
+            ink::env::test::set_caller(player2);
+            let result = contract.request_cash_out(player1);
+            assert!(matches!(result, Err(Error::NotAuthorized)));
 
-            // Player should not be registered initially
-            assert!(!contract.is_player_registered(player_address));
+            // Attesting requires a pending request and the GameServer role
+            ink::env::test::set_caller(server);
+            let result = contract.attest_cash_out(player1, 1200);
+            assert!(matches!(result, Err(Error::CashOutNotRequested)));
 
-            // Test with different address
-            let other_address = H160::from([2; 20]);
-            assert!(!contract.is_player_registered(other_address));
-        }
+            ink::env::test::set_caller(session_key);
+            contract.request_cash_out(player1).unwrap();
 
-        /// Test time remaining functions.
-        #[ink::test]
-        fn time_remaining_functions_work() {
-            let contract = AgarioBuyin::new(5).unwrap();
+            ink::env::test::set_caller(admin);
+            let result = contract.attest_cash_out(player1, 1200);
+            assert!(matches!(result, Err(Error::MissingRole)));
 
-            // Should return 0 when game is inactive
-            assert_eq!(contract.get_registration_time_remaining(), 0);
-            assert_eq!(contract.get_game_time_remaining(), None);
+            ink::env::test::set_caller(server);
+            let result = contract.attest_cash_out(player1, 2500);
+            assert!(matches!(result, Err(Error::InsufficientPrizePool)));
+            contract.attest_cash_out(player1, 1200).unwrap();
+
+            // Player left the game and took their value out of the pool
+            assert!(!contract.is_player_registered(player1));
+            assert_eq!(contract.get_player_count(), 1);
+            assert_eq!(contract.get_prize_pool(), 800);
         }
 
-        /// Test start_game function works correctly.
+        /// Test cash-outs are rejected outside cash games
         #[ink::test]
-        fn start_game_works() {
+        fn cash_out_requires_cash_game() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let player = H160::from([1; 20]);
 
-            // Should work with valid parameters
-            let result = contract.start_game(1000, 5, 2, Some(10));
-            assert!(matches!(result, Ok(())));
+            contract.start_game(1000, 5, 2, None).unwrap();
+            deposit_as(&mut contract, player, 1000).unwrap();
+            contract.game_state = GameState::InProgress;
 
-            // Check state changed
-            assert_eq!(contract.get_game_state(), GameState::AcceptingDeposits);
-            assert_eq!(contract.get_buy_in_amount(), 1000);
-            assert_eq!(contract.get_min_players(), 2);
+            let result = contract.request_cash_out(player);
+            assert!(matches!(result, Err(Error::NotCashGame)));
         }
 
-        /// Test start_game validation.
+        /// Test bounties move to the eliminator on elimination
         #[ink::test]
-        fn start_game_validates_parameters() {
+        fn bounty_credited_on_elimination() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let server = H160::from([50; 20]);
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let player3 = H160::from([3; 20]);
 
-            // Should fail with too few players
-            let result = contract.start_game(1000, 5, 1, Some(10));
-            assert!(matches!(result, Err(Error::TooFewPlayers)));
+            contract.grant_role(Role::GameServer, server).unwrap();
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                registration_period: 5,
+                bounty_percentage: 20,
+                ..Default::default()
+            };
+            contract.start_game_with_config(config).unwrap();
+            for player in [player1, player2, player3] {
+                deposit_as(&mut contract, player, 1000).unwrap();
+            }
 
-            // Start a valid game
-            contract.start_game(1000, 5, 2, Some(10)).unwrap();
+            // 20% of each buy-in is set aside as bounty
+            assert_eq!(contract.get_prize_pool(), 2400);
+            assert_eq!(contract.get_bounty_pool(), 600);
+            assert_eq!(contract.get_player_bounty(player2), 200);
+            contract.game_state = GameState::InProgress;
 
-            // Should fail if game already started
-            let result = contract.start_game(2000, 5, 2, Some(10));
-            assert!(matches!(result, Err(Error::GameNotInCorrectState)));
+            // Only the game server reports eliminations
+            ink::env::test::set_caller(player1);
+            let result = contract.report_eliminations(vec![(player1, player2)]);
+            assert!(matches!(result, Err(Error::MissingRole)));
+
+            ink::env::test::set_caller(server);
+            let result = contract.report_eliminations(vec![(player1, player1)]);
+            assert!(matches!(result, Err(Error::InvalidElimination)));
+            contract
+                .report_eliminations(vec![(player1, player2)])
+                .unwrap();
+            assert_eq!(contract.get_player_bounty(player2), 0);
+            assert_eq!(contract.get_claimable(player1), 200);
+            assert_eq!(contract.get_bounty_pool(), 400);
+
+            // Eliminator claims the bounty
+            ink::env::test::set_caller(player1);
+            contract.claim().unwrap();
+            assert_eq!(contract.get_claimable(player1), 0);
+            assert!(matches!(contract.claim(), Err(Error::NothingToClaim)));
         }
 
-        /// Test deposit function works correctly.
+        /// Test a refund returns each player what they paid, less bounties already paid out
         #[ink::test]
-        fn deposit_works() {
+        fn refund_returns_each_player_what_they_paid() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let admin = contract.get_admin();
+            let server = H160::from([50; 20]);
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let player3 = H160::from([3; 20]);
 
-            // Start a game first
-            contract.start_game(1000, 5, 2, Some(10)).unwrap();
-
-            // Mock the deposit by setting transferred value
-            // Note: In actual tests, this would be handled by the test environment
-            // For unit tests, we'll test the logic validation indirectly
+            contract.grant_role(Role::GameServer, server).unwrap();
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                registration_period: 5,
+                bounty_percentage: 20,
+                ..Default::default()
+            };
+            contract.start_game_with_config(config).unwrap();
+            for player in [player1, player2, player3] {
+                deposit_as(&mut contract, player, 1000).unwrap();
+            }
+            contract.game_state = GameState::InProgress;
+            ink::env::test::set_caller(server);
+            contract
+                .report_eliminations(vec![(player1, player2)])
+                .unwrap();
+
+            // The bounty already paid to player1 is not refunded to player2 again
+            ink::env::test::set_caller(admin);
+            contract.force_end_game().unwrap();
+            assert_eq!(contract.get_claimable(player1), 200 + 1000);
+            assert_eq!(contract.get_claimable(player2), 800);
+            assert_eq!(contract.get_claimable(player3), 1000);
+            assert_eq!(contract.get_player_stats(player2).total_refunded, 800);
+
+            // Entry fees are refunded even when the stake is zero
+            let config = GameConfig {
+                buy_in: 0,
+                entry_fee: 100,
+                min_players: 2,
+                registration_period: 5,
+                ..Default::default()
+            };
+            contract.start_game_with_config(config).unwrap();
+            deposit_as(&mut contract, player3, 100).unwrap();
+            ink::env::test::set_caller(admin);
+            contract.force_end_game().unwrap();
+            assert_eq!(contract.get_claimable(player3), 1100);
+            assert_eq!(contract.get_entry_fees(), 0);
         }
 
-        /// Test game flow transitions.
+        /// Test alive-set tracking ends the game on the last player standing
         #[ink::test]
-        fn game_flow_transitions_work() {
+        fn last_player_standing_detected_from_eliminations() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let server = H160::from([50; 20]);
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let player3 = H160::from([3; 20]);
 
-            // Start with inactive state
-            assert_eq!(contract.get_game_state(), GameState::Inactive);
-
-            // Start game
-            contract.start_game(1000, 5, 2, Some(10)).unwrap();
-            assert_eq!(contract.get_game_state(), GameState::AcceptingDeposits);
+            contract.grant_role(Role::GameServer, server).unwrap();
+            contract.start_game(1000, 5, 2, None).unwrap();
+            for player in [player1, player2, player3] {
+                deposit_as(&mut contract, player, 1000).unwrap();
+            }
+            contract.registration_deadline = 0;
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.get_alive_count(), 3);
+
+            // First batch eliminates player3
+            ink::env::test::set_caller(server);
+            contract
+                .report_eliminations(vec![(player1, player3)])
+                .unwrap();
+            assert_eq!(contract.get_alive_count(), 2);
+            assert!(!contract.is_player_alive(player3));
+            assert_eq!(contract.get_elimination_order(player3), Some(1));
+            assert_eq!(contract.get_game_state(), GameState::InProgress);
 
-            // Test try_begin_game with insufficient time passed
-            let result = contract.try_begin_game();
-            assert!(matches!(result, Ok(())));
-            // Should still be accepting deposits if deadline not passed
+            // Eliminated players cannot be eliminated again or eliminate others
+            let result = contract.report_eliminations(vec![(player1, player3)]);
+            assert!(matches!(result, Err(Error::InvalidElimination)));
+            let result = contract.report_eliminations(vec![(player3, player2)]);
+            assert!(matches!(result, Err(Error::InvalidElimination)));
+
+            // Last elimination ends the game
+            contract
+                .report_eliminations(vec![(player1, player2)])
+                .unwrap();
+            assert_eq!(contract.get_alive_count(), 1);
+            assert!(contract.is_player_alive(player1));
+            assert_eq!(contract.get_eliminated_player(1), Some(player3));
+            assert_eq!(contract.get_eliminated_player(2), Some(player2));
+            assert_eq!(contract.get_game_state(), GameState::WaitingForResults);
         }
 
-        /// Test admin functions require admin access.
+        /// Test team registration and team settlement
         #[ink::test]
-        fn admin_functions_require_admin() {
+        fn team_mode_works() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let admin = contract.get_admin();
+            let server = H160::from([50; 20]);
+            let players: Vec<H160> = (1..=4).map(|i| H160::from([i; 20])).collect();
+
+            contract.grant_role(Role::GameServer, server).unwrap();
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                registration_period: 5,
+                teams: Some(TeamConfig {
+                    team_count: 2,
+                    min_team_size: 2,
+                    max_team_size: 2,
+                    split: TeamSplit::Contribution,
+                }),
+                ..Default::default()
+            };
+            contract.start_game_with_config(config).unwrap();
+
+            // Team games need a valid team id
+            let result = deposit_as(&mut contract, players[0], 1000);
+            assert!(matches!(result, Err(Error::TeamRequired)));
+            ink::env::test::set_caller(players[0]);
+            set_value(1000);
+            assert!(matches!(
+                contract.deposit_to_team(2),
+                Err(Error::InvalidTeam)
+            ));
 
-            // These functions should only work for admin
-            // In actual implementation, you'd mock a different caller
+            for (i, player) in players.iter().enumerate() {
+                ink::env::test::set_caller(*player);
+                contract.deposit_to_team((i / 2) as u32).unwrap();
+            }
+            assert_eq!(contract.get_team_members(0), vec![players[0], players[1]]);
+            assert_eq!(contract.get_player_info(players[2]).unwrap().team, Some(1));
 
-            // start_game should work for admin (constructor caller)
-            let result = contract.start_game(1000, 5, 2, Some(10));
-            assert!(matches!(result, Ok(())));
+            // Teams are capped at max_team_size
+            ink::env::test::set_caller(H160::from([5; 20]));
+            assert!(matches!(contract.deposit_to_team(0), Err(Error::TeamFull)));
+
+            // Both teams meet the minimum size so the game begins
+            contract.registration_deadline = 0;
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.get_game_state(), GameState::InProgress);
+
+            ink::env::test::set_caller(server);
+            contract
+                .report_contributions(vec![(players[0], 3), (players[1], 1)])
+                .unwrap();
+            assert_eq!(
+                contract.get_player_info(players[0]).unwrap().contribution,
+                3
+            );
+
+            // Settlement ranks teams
+            ink::env::test::set_caller(admin);
+            contract.game_state = GameState::WaitingForResults;
+            let result =
+                contract.submit_team_results(vec![0, 5], vec![70, 30], GameEndReason::TimeLimit);
+            assert!(matches!(result, Err(Error::InvalidTeam)));
+            let result =
+                contract.submit_team_results(vec![0, 0], vec![70, 30], GameEndReason::TimeLimit);
+            assert!(matches!(result, Err(Error::DuplicateTeam)));
+            contract
+                .submit_team_results(vec![0, 1], vec![70, 30], GameEndReason::TimeLimit)
+                .unwrap();
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
+
+            // Winner pool is 3800: team 0 splits 2660 by contribution 3:1,
+            // team 1 reported no contributions and splits 1140 equally
+            let winnings: Vec<Balance> = players
+                .iter()
+                .map(|player| contract.get_player_stats(*player).total_winnings)
+                .collect();
+            assert_eq!(winnings, vec![1995, 665, 570, 570]);
         }
 
-        /// Test winner submission validation.
+        /// Test team settlement rejects empty teams and credits members with their team's placement
         #[ink::test]
-        fn submit_winners_validates_input() {
+        fn team_results_rank_members() {
             let mut contract = AgarioBuyin::new(5).unwrap();
-
-            // Start game and move to WaitingForResults state
-            contract.start_game(1000, 5, 2, Some(10)).unwrap();
+            let admin = contract.get_admin();
+            let players: Vec<H160> = (1..=4).map(|i| H160::from([i; 20])).collect();
+
+            contract.set_trophy_places(2).unwrap();
+            contract
+                .start_season(0, 1_000_000, vec![10, 5], 5, vec![100])
+                .unwrap();
+            let season_id = contract.get_season().unwrap().id;
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                registration_period: 5,
+                teams: Some(TeamConfig {
+                    team_count: 3,
+                    min_team_size: 1,
+                    max_team_size: 2,
+                    split: TeamSplit::Equal,
+                }),
+                ..Default::default()
+            };
+            contract.start_game_with_config(config).unwrap();
+            for (i, player) in players.iter().enumerate() {
+                ink::env::test::set_caller(*player);
+                set_value(1000);
+                contract.deposit_to_team((i / 2) as u32).unwrap();
+            }
+            ink::env::test::set_caller(admin);
             contract.game_state = GameState::WaitingForResults;
-            contract.prize_pool = 1000;
-
-            // Test empty winners
-            let result = contract.submit_winners(vec![], vec![], GameEndReason::TimeLimit);
-            assert!(matches!(result, Err(Error::NoWinners)));
 
-            // Test mismatched vectors
-            let winners = vec![H160::from([1; 20])];
-            let percentages = vec![50, 30]; // Different length
-            let result = contract.submit_winners(winners, percentages, GameEndReason::TimeLimit);
-            assert!(matches!(result, Err(Error::MismatchedData)));
+            // Team 2 has no members, so its share would be stranded
+            let result =
+                contract.submit_team_results(vec![1, 2], vec![70, 30], GameEndReason::TimeLimit);
+            assert!(matches!(result, Err(Error::InvalidTeam)));
+            contract
+                .submit_team_results(vec![1, 0], vec![70, 30], GameEndReason::TimeLimit)
+                .unwrap();
+
+            // Members share their team's placement
+            for (player, placement, points) in [(players[2], 1, 10), (players[0], 2, 5)] {
+                let trophies = contract.get_player_trophies(player);
+                assert_eq!(trophies.len(), 1);
+                assert_eq!(trophies[0].placement, placement);
+                assert_eq!(contract.get_season_points(season_id, player), points);
+            }
+            assert_eq!(contract.get_badge(players[2], Badge::FirstWin), Some(1));
+            assert_eq!(contract.get_badge(players[3], Badge::FirstWin), Some(1));
+            assert_eq!(contract.get_badge(players[0], Badge::FirstWin), None);
+            assert!(contract.get_rating(players[3]) > DEFAULT_RATING);
+            assert!(contract.get_rating(players[1]) < DEFAULT_RATING);
+        }
 
-            // Test invalid percentages
-            let winners = vec![H160::from([1; 20]), H160::from([2; 20])];
-            let percentages = vec![60, 50]; // Total > 100
-            let result = contract.submit_winners(winners, percentages, GameEndReason::TimeLimit);
-            assert!(matches!(result, Err(Error::InvalidPercentages)));
+        /// Test a team game does not begin while a team is below minimum size
+        #[ink::test]
+        fn team_game_requires_min_team_size() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                registration_period: 5,
+                teams: Some(TeamConfig {
+                    team_count: 2,
+                    min_team_size: 1,
+                    max_team_size: 3,
+                    split: TeamSplit::Equal,
+                }),
+                ..Default::default()
+            };
+            contract.start_game_with_config(config).unwrap();
+            for i in 1..=2 {
+                ink::env::test::set_caller(H160::from([i; 20]));
+                set_value(1000);
+                contract.deposit_to_team(0).unwrap();
+            }
+
+            // Team 1 is empty, so everyone is refunded
+            contract.registration_deadline = 0;
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
         }
 
-        /// Test check_game_conditions function for automatic state transitions
+        /// Test a two-stage tournament from registration to final table
         #[ink::test]
-        fn check_game_conditions_handles_transitions() {
+        fn tournament_bracket_works() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let admin = contract.get_admin();
+            let players: Vec<H160> = (1..=6).map(|i| H160::from([i; 20])).collect();
+
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 4,
+                registration_period: 5,
+                ..Default::default()
+            };
+            contract
+                .start_tournament(config, 3, 1, vec![70, 30])
+                .unwrap();
+
+            // Registration uses the normal deposit flow
+            for player in players.iter() {
+                deposit_as(&mut contract, *player, 1000).unwrap();
+            }
+            assert_eq!(contract.get_tournament_entrants(1), players);
+            let ticket = contract
+                .get_player_info(players[0])
+                .unwrap()
+                .ticket
+                .unwrap();
+
+            // Registration closes and the pool moves to the tournament
+            ink::env::test::set_caller(admin);
+            contract.registration_deadline = 0;
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
+            let tournament = contract.get_tournament().unwrap();
+            assert_eq!(tournament.stage, 1);
+            assert_eq!(tournament.pool, 6000);
+            // Entry tickets are held until the tournament ends
+            assert_eq!(contract.ticket_owner_of(ticket), Some(players[0]));
+
+            // Stage 1: two tables of three, the winner of each advances
+            for table in 0..2 {
+                contract.start_tournament_table().unwrap();
+                assert_eq!(contract.get_game_state(), GameState::InProgress);
+                assert_eq!(contract.get_player_count(), 3);
+                assert!(contract.is_player_registered(players[table * 3]));
+                contract
+                    .report_game_end(GameEndReason::LastPlayerStanding)
+                    .unwrap();
+                contract
+                    .submit_winners(
+                        vec![players[table * 3]],
+                        vec![],
+                        GameEndReason::LastPlayerStanding,
+                    )
+                    .unwrap();
+            }
+            assert_eq!(contract.get_tournament().unwrap().stage, 2);
+            assert_eq!(
+                contract.get_tournament_entrants(2),
+                vec![players[0], players[3]]
+            );
 
-            // Test 1: Transition from AcceptingDeposits to InProgress
-            // Use a past timestamp so the deadline is definitely passed
-            contract.start_game(1000, 0, 2, Some(10)).unwrap(); // registration_deadline = 0 (immediate)
-            contract.player_count = 3; // Enough players
+            // Stage 2 is the final table paid from the tournament pool
+            contract.start_tournament_table().unwrap();
+            assert!(contract.get_tournament().unwrap().final_table);
+            contract
+                .report_game_end(GameEndReason::LastPlayerStanding)
+                .unwrap();
+            let result = contract.submit_winners(
+                vec![players[3], players[3]],
+                vec![],
+                GameEndReason::LastPlayerStanding,
+            );
+            assert!(matches!(result, Err(Error::DuplicatePlacement)));
+            let result = contract.submit_winners(
+                vec![players[1]],
+                vec![],
+                GameEndReason::LastPlayerStanding,
+            );
+            assert!(matches!(result, Err(Error::PlayerNotRegistered)));
+            contract
+                .submit_winners(
+                    vec![players[3], players[0]],
+                    vec![],
+                    GameEndReason::LastPlayerStanding,
+                )
+                .unwrap();
+            assert_eq!(contract.get_tournament(), None);
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
+            assert_eq!(contract.ticket_owner_of(ticket), None);
+            assert_eq!(contract.ticket_balance_of(players[0]), 0);
+        }
 
-            // Force the registration deadline to be in the past by setting it manually
-            contract.registration_deadline = 0; // Definitely in the past
+        /// Test tournament configuration validation and cancellation
+        #[ink::test]
+        fn tournament_validates_and_cancels() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                registration_period: 5,
+                ..Default::default()
+            };
+
+            // Advancing the whole table is not a bracket
+            let result = contract.start_tournament(config.clone(), 3, 3, vec![100]);
+            assert!(matches!(result, Err(Error::InvalidTournamentConfig)));
+
+            contract
+                .start_tournament(config.clone(), 3, 1, vec![100])
+                .unwrap();
+            let result = contract.start_tournament(config.clone(), 3, 1, vec![100]);
+            assert!(matches!(result, Err(Error::TournamentInProgress)));
+
+            deposit_as(&mut contract, H160::from([1; 20]), 1000).unwrap();
+            ink::env::test::set_caller(contract.get_admin());
+            contract.cancel_tournament().unwrap();
+            assert_eq!(contract.get_tournament(), None);
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
+            assert_eq!(contract.get_claimable(H160::from([1; 20])), 1000);
+            assert_eq!(contract.ticket_balance_of(H160::from([1; 20])), 0);
+            assert!(matches!(
+                contract.cancel_tournament(),
+                Err(Error::NoTournament)
+            ));
 
-            // Should transition to InProgress
-            let result = contract.check_game_conditions();
-            assert!(matches!(result, Ok(())));
-            assert_eq!(contract.get_game_state(), GameState::InProgress);
-            // Note: game_start_time will be set to current block timestamp (likely 0 in test env)
+            // Cancelling after registration closed burns the held entry tickets
+            contract.start_tournament(config, 3, 1, vec![100]).unwrap();
+            deposit_as(&mut contract, H160::from([1; 20]), 1000).unwrap();
+            deposit_as(&mut contract, H160::from([2; 20]), 1000).unwrap();
+            ink::env::test::set_caller(contract.get_admin());
+            contract.registration_deadline = 0;
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.ticket_balance_of(H160::from([2; 20])), 1);
+            contract.cancel_tournament().unwrap();
+            assert_eq!(contract.ticket_balance_of(H160::from([2; 20])), 0);
+        }
 
-            // Test 2: Game duration expiry transition
-            // Set up a scenario where game duration has expired
-            contract.game_state = GameState::InProgress;
-            contract.game_start_time = 0; // Game started at time 0
-            contract.game_duration = Some(0); // Duration is 0, so immediately expired
+        /// Test a cancelled tournament returns stakes to their payers and the overlay to the treasury
+        #[ink::test]
+        fn cancelled_tournament_refunds_payers_and_overlay() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let admin = contract.get_admin();
+            let payer = H160::from([9; 20]);
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                registration_period: 5,
+                guaranteed_pool: Some(4000),
+                ..Default::default()
+            };
+
+            set_value(5000);
+            contract.fund_treasury().unwrap();
+
+            // Cancelled during registration: the payer gets the buy-in back
+            contract
+                .start_tournament(config.clone(), 3, 1, vec![100])
+                .unwrap();
+            ink::env::test::set_caller(payer);
+            set_value(1000);
+            contract.deposit_for(player1).unwrap();
+            ink::env::test::set_caller(admin);
+            contract.force_end_game().unwrap();
+            assert_eq!(contract.get_tournament(), None);
+            assert_eq!(contract.get_claimable(payer), 1000);
+            assert_eq!(contract.get_claimable(player1), 0);
+            assert_eq!(contract.get_treasury_balance(), 5000);
+
+            // Cancelled after registration: stakes follow their payers, the overlay is returned
+            contract.start_tournament(config, 3, 1, vec![100]).unwrap();
+            ink::env::test::set_caller(payer);
+            set_value(1000);
+            contract.deposit_for(player1).unwrap();
+            deposit_as(&mut contract, player2, 1000).unwrap();
+            ink::env::test::set_caller(admin);
+            contract.registration_deadline = 0;
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.get_tournament().unwrap().pool, 4000);
+            assert_eq!(contract.get_treasury_balance(), 3000);
+            contract.start_tournament_table().unwrap();
+            contract.force_end_game().unwrap();
+            assert_eq!(contract.get_tournament(), None);
+            assert_eq!(contract.get_claimable(payer), 2000);
+            assert_eq!(contract.get_claimable(player1), 0);
+            assert_eq!(contract.get_claimable(player2), 1000);
+            assert_eq!(contract.get_treasury_balance(), 5000);
+
+            // Refund statistics follow the wallet that was credited
+            let stats = contract.get_player_stats(payer);
+            assert_eq!((stats.refunds, stats.total_refunded), (2, 2000));
+            let stats = contract.get_player_stats(player1);
+            assert_eq!((stats.refunds, stats.total_refunded), (0, 0));
+            let stats = contract.get_player_stats(player2);
+            assert_eq!((stats.refunds, stats.total_refunded), (1, 1000));
+        }
 
-            // Should transition to WaitingForResults
-            let result = contract.check_game_conditions();
-            assert!(matches!(result, Ok(())));
-            assert_eq!(contract.get_game_state(), GameState::WaitingForResults);
+        /// Test a tournament whose registration does not fill is ended with the refund
+        #[ink::test]
+        fn unfilled_tournament_registration_ends_tournament() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let admin = contract.get_admin();
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 4,
+                registration_period: 5,
+                ..Default::default()
+            };
+
+            contract.start_tournament(config, 3, 1, vec![100]).unwrap();
+            deposit_as(&mut contract, player1, 1000).unwrap();
+            contract.registration_deadline = 0;
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.get_tournament(), None);
+            assert_eq!(contract.get_claimable(player1), 1000);
+
+            // The next regular game is not mistaken for tournament registration
+            ink::env::test::set_caller(admin);
+            contract.start_game(1000, 5, 2, None).unwrap();
+            deposit_as(&mut contract, player1, 1000).unwrap();
+            deposit_as(&mut contract, player2, 1000).unwrap();
+            contract.registration_deadline = 0;
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.get_game_state(), GameState::InProgress);
+            assert_eq!(contract.get_prize_pool(), 2000);
         }
 
-        /// Test check_game_conditions refund logic
+        /// Test sponsor contributions are recorded and returned on refund
         #[ink::test]
-        fn check_game_conditions_refunds_on_insufficient_players() {
+        fn sponsor_pool_works_and_refunds() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let sponsor = H160::from([70; 20]);
 
-            // Start game with immediate deadline but insufficient players
-            contract.start_game(1000, 0, 5, Some(10)).unwrap(); // Need 5 players
-            contract.player_count = 2; // Only 2 players
+            contract.start_game(1000, 5, 3, None).unwrap();
+            let round = contract.get_current_round();
+            deposit_as(&mut contract, H160::from([1; 20]), 1000).unwrap();
 
-            // Should refund and reset to Inactive
-            let result = contract.check_game_conditions();
-            assert!(matches!(result, Ok(())));
+            // Sponsors must target the current round and send funds
+            ink::env::test::set_caller(sponsor);
+            set_value(5000);
+            assert!(matches!(
+                contract.sponsor_pool(round + 1),
+                Err(Error::InvalidRound)
+            ));
+            contract.sponsor_pool(round).unwrap();
+            contract.sponsor_pool(round).unwrap();
+            set_value(0);
+            assert!(matches!(
+                contract.sponsor_pool(round),
+                Err(Error::NoValueTransferred)
+            ));
+            assert_eq!(contract.get_sponsor_contribution(round, sponsor), 10000);
+            assert_eq!(contract.get_sponsored_amount(), 10000);
+            assert_eq!(contract.get_prize_pool(), 11000);
+
+            // Too few players: the game is refunded and the sponsor gets their funds back
+            contract.registration_deadline = 0;
+            contract.check_game_conditions().unwrap();
             assert_eq!(contract.get_game_state(), GameState::Inactive);
-            assert_eq!(contract.player_count, 0);
+            assert_eq!(contract.get_claimable(sponsor), 10000);
+
+            // Tournaments cannot be sponsored
+            ink::env::test::set_caller(contract.get_admin());
+            contract
+                .start_tournament(
+                    GameConfig {
+                        buy_in: 1000,
+                        min_players: 2,
+                        registration_period: 5,
+                        ..Default::default()
+                    },
+                    3,
+                    1,
+                    vec![100],
+                )
+                .unwrap();
+            let round = contract.get_current_round();
+            ink::env::test::set_caller(sponsor);
+            set_value(3000);
+            assert!(matches!(
+                contract.sponsor_pool(round),
+                Err(Error::TournamentInProgress)
+            ));
+            ink::env::test::set_caller(contract.get_admin());
+            contract.cancel_tournament().unwrap();
+
+            // A sponsored game nobody joins still returns the contribution
+            ink::env::test::set_caller(contract.get_admin());
+            contract.start_game(1000, 5, 3, None).unwrap();
+            let round = contract.get_current_round();
+            ink::env::test::set_caller(sponsor);
+            set_value(3000);
+            contract.sponsor_pool(round).unwrap();
+            set_value(0);
+            contract.registration_deadline = 0;
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
+            assert_eq!(contract.get_claimable(sponsor), 13000);
         }
 
-        /// Test check_game_conditions no-op for other states
+        /// Test the treasury tops the pool up to the guarantee
         #[ink::test]
-        fn check_game_conditions_no_op_for_other_states() {
+        fn guaranteed_pool_overlay_works() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                registration_period: 5,
+                guaranteed_pool: Some(10000),
+                ..Default::default()
+            };
+
+            // The guarantee must be covered by the treasury
+            let result = contract.start_game_with_config(config.clone());
+            assert!(matches!(result, Err(Error::InsufficientTreasury)));
+
+            set_value(12000);
+            contract.fund_treasury().unwrap();
+            contract.start_game_with_config(config).unwrap();
+            assert_eq!(contract.get_treasury_balance(), 2000);
+
+            for i in 1..=3 {
+                deposit_as(&mut contract, H160::from([i; 20]), 1000).unwrap();
+            }
+            contract.registration_deadline = 0;
+            contract.check_game_conditions().unwrap();
+            assert_eq!(contract.get_game_state(), GameState::InProgress);
+            assert_eq!(contract.get_prize_pool(), 10000);
+            assert_eq!(contract.get_overlay(), 7000);
+            assert_eq!(contract.get_total_overlay(), 7000);
 
-            // Test Inactive state
-            let result = contract.check_game_conditions();
-            assert!(matches!(result, Ok(())));
-            assert_eq!(contract.get_game_state(), GameState::Inactive);
-
-            // Test WaitingForResults state
+            // Unused reserve is released when the game ends
+            ink::env::test::set_caller(contract.get_admin());
             contract.game_state = GameState::WaitingForResults;
-            let result = contract.check_game_conditions();
-            assert!(matches!(result, Ok(())));
-            assert_eq!(contract.get_game_state(), GameState::WaitingForResults);
+            contract
+                .submit_winners(
+                    vec![H160::from([1; 20])],
+                    vec![100],
+                    GameEndReason::TimeLimit,
+                )
+                .unwrap();
+            assert_eq!(contract.get_treasury_balance(), 5000);
         }
 
-        /// Test reset game state function.
+        /// Test jackpot grows across rounds and pays on the win condition
         #[ink::test]
-        fn reset_game_state_works() {
+        fn jackpot_accumulates_and_pays_out() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let winner = H160::from([1; 20]);
+
+            contract
+                .set_jackpot_config(JackpotConfig {
+                    percentage: 2,
+                    min_players: Some(4),
+                })
+                .unwrap();
+
+            // Two small games feed the jackpot without triggering it
+            for _ in 0..2 {
+                contract.start_game(1000, 5, 2, None).unwrap();
+                seat_players(&mut contract, &[winner]);
+                contract.game_state = GameState::WaitingForResults;
+                contract.prize_pool = 10000;
+                contract.player_count = 2;
+                contract
+                    .submit_winners(vec![winner], vec![100], GameEndReason::TimeLimit)
+                    .unwrap();
+            }
+            assert_eq!(contract.get_jackpot(), 400);
+            assert_eq!(contract.get_jackpot_contribution_count(), 2);
+            assert_eq!(
+                contract.get_jackpot_contributions(1, 10),
+                vec![JackpotContribution {
+                    round: 2,
+                    amount: 200
+                }]
+            );
+            assert_eq!(contract.get_claimable(winner), 0);
 
-            // Set up some state
-            contract.start_game(1000, 5, 2, Some(10)).unwrap();
-            assert_eq!(contract.get_game_state(), GameState::AcceptingDeposits);
-
-            // Reset state
-            contract.reset_game_state();
+            // A large enough game pays the jackpot to first place
+            contract.start_game(1000, 5, 2, None).unwrap();
+            seat_players(&mut contract, &[winner]);
+            contract.game_state = GameState::WaitingForResults;
+            contract.prize_pool = 10000;
+            contract.player_count = 4;
+            contract
+                .submit_winners(vec![winner], vec![100], GameEndReason::TimeLimit)
+                .unwrap();
+            assert_eq!(contract.get_jackpot(), 0);
+            assert_eq!(contract.get_claimable(winner), 600);
+        }
 
-            // Check everything is reset
-            assert_eq!(contract.get_game_state(), GameState::Inactive);
-            assert_eq!(contract.get_buy_in_amount(), 0);
-            assert_eq!(contract.get_player_count(), 0);
-            assert_eq!(contract.get_prize_pool(), 0);
+        /// Test team and tournament settlement trigger the jackpot too
+        #[ink::test]
+        fn jackpot_pays_out_on_team_and_tournament_settlement() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let admin = contract.get_admin();
+            let players: Vec<H160> = (1..=4).map(|i| H160::from([i; 20])).collect();
+
+            contract
+                .set_jackpot_config(JackpotConfig {
+                    percentage: 0,
+                    min_players: Some(4),
+                })
+                .unwrap();
+
+            // The winning team splits the jackpot
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                registration_period: 5,
+                teams: Some(TeamConfig {
+                    team_count: 2,
+                    min_team_size: 2,
+                    max_team_size: 2,
+                    split: TeamSplit::Equal,
+                }),
+                ..Default::default()
+            };
+            contract.start_game_with_config(config).unwrap();
+            for (i, player) in players.iter().enumerate() {
+                ink::env::test::set_caller(*player);
+                set_value(1000);
+                contract.deposit_to_team((i / 2) as u32).unwrap();
+            }
+            ink::env::test::set_caller(admin);
+            contract.jackpot = 1000;
+            contract.game_state = GameState::WaitingForResults;
+            contract
+                .submit_team_results(vec![1], vec![100], GameEndReason::TimeLimit)
+                .unwrap();
+            assert_eq!(contract.get_jackpot(), 0);
+            assert_eq!(contract.get_claimable(players[0]), 0);
+            assert_eq!(contract.get_claimable(players[2]), 1900 + 500);
+            assert_eq!(contract.get_claimable(players[3]), 1900 + 500);
+
+            // The tournament winner takes the jackpot at the final table
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 4,
+                registration_period: 5,
+                ..Default::default()
+            };
+            contract.start_tournament(config, 2, 1, vec![100]).unwrap();
+            for player in players.iter() {
+                deposit_as(&mut contract, *player, 1000).unwrap();
+            }
+            ink::env::test::set_caller(admin);
+            contract.registration_deadline = 0;
+            contract.check_game_conditions().unwrap();
+            for table in 0..2 {
+                contract.start_tournament_table().unwrap();
+                contract.game_state = GameState::WaitingForResults;
+                contract
+                    .submit_winners(
+                        vec![players[table * 2]],
+                        vec![],
+                        GameEndReason::LastPlayerStanding,
+                    )
+                    .unwrap();
+            }
+            contract.start_tournament_table().unwrap();
+            assert!(contract.get_tournament().unwrap().final_table);
+            contract.jackpot = 700;
+            contract.game_state = GameState::WaitingForResults;
+            contract
+                .submit_winners(vec![players[0]], vec![], GameEndReason::LastPlayerStanding)
+                .unwrap();
+            assert_eq!(contract.get_tournament(), None);
+            assert_eq!(contract.get_jackpot(), 0);
+            assert_eq!(contract.get_claimable(players[0]), 3800 + 700);
         }
 
+        /// Test server-attested jackpot award
         #[ink::test]
-        fn submit_winners_distributes_prizes_correctly() {
+        fn award_jackpot_requires_game_server() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let server = H160::from([50; 20]);
+            let player = H160::from([1; 20]);
 
-            // Setup game with multiple players
-            let _ = contract.start_game(1000, 5, 2, Some(60));
-            contract.game_state = GameState::WaitingForResults;
-            contract.prize_pool = 10000; // 10 DOT total prize pool
-            contract.player_count = 4;
+            contract.grant_role(Role::GameServer, server).unwrap();
+            contract.start_game(1000, 5, 2, None).unwrap();
+            deposit_as(&mut contract, player, 1000).unwrap();
+            contract.game_state = GameState::InProgress;
 
-            // Define winners: 1st place 50%, 2nd place 30%, 3rd place 20%
-            let winners = vec![
-                H160::from([1; 20]), // 1st place
-                H160::from([2; 20]), // 2nd place
-                H160::from([3; 20]), // 3rd place
-            ];
-            let percentages = vec![50, 30, 20]; // Total 100%
+            ink::env::test::set_caller(server);
+            assert!(matches!(
+                contract.award_jackpot(player),
+                Err(Error::JackpotEmpty)
+            ));
+            contract.jackpot = 300;
+            ink::env::test::set_caller(player);
+            assert!(matches!(
+                contract.award_jackpot(player),
+                Err(Error::MissingRole)
+            ));
+            ink::env::test::set_caller(server);
+            contract.award_jackpot(player).unwrap();
+            assert_eq!(contract.get_claimable(player), 300);
+        }
 
-            // Submit winners
-            let result = contract.submit_winners(
-                winners.clone(),
-                percentages.clone(),
-                GameEndReason::TimeLimit,
-            );
-            assert!(result.is_ok());
+        /// Test season points, leaderboard pagination and season payout
+        #[ink::test]
+        fn season_leaderboard_works() {
+            let mut contract = AgarioBuyin::new(10).unwrap();
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let player3 = H160::from([3; 20]);
 
-            // Verify game state reset
-            assert_eq!(contract.game_state, GameState::Inactive);
-            assert_eq!(contract.prize_pool, 0);
-            assert_eq!(contract.player_count, 0);
+            let result = contract.start_season(100, 50, vec![10], 5, vec![100]);
+            assert!(matches!(result, Err(Error::InvalidSeasonConfig)));
+            contract
+                .start_season(0, 1_000_000, vec![10, 5, 2], 5, vec![60, 40])
+                .unwrap();
+            let season_id = contract.get_season().unwrap().id;
+
+            // Two settled games award points by placement
+            let results = [vec![player1, player2, player3], vec![player3, player1]];
+            for winners in results {
+                contract.start_game(1000, 5, 2, None).unwrap();
+                seat_players(&mut contract, &winners);
+                contract.game_state = GameState::WaitingForResults;
+                contract.prize_pool = 10000;
+                let percentages = vec![0; winners.len()];
+                contract
+                    .submit_winners(winners, percentages, GameEndReason::TimeLimit)
+                    .unwrap();
+            }
+            assert_eq!(contract.get_season_points(season_id, player1), 15);
+            assert_eq!(contract.get_season_points(season_id, player3), 12);
+            assert_eq!(contract.get_season().unwrap().pool, 1000);
+
+            // Leaderboard is ranked by points and paginated
+            assert_eq!(
+                contract.get_season_leaderboard(0, 2),
+                vec![(player1, 15), (player3, 12)]
+            );
+            assert_eq!(contract.get_season_leaderboard(2, 2), vec![(player2, 5)]);
+
+            // Season cannot end early, then pays the top two
+            assert!(matches!(contract.end_season(), Err(Error::SeasonNotOver)));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000_000);
+            contract.end_season().unwrap();
+            assert_eq!(contract.get_claimable(player1), 600);
+            assert_eq!(contract.get_claimable(player3), 400);
+            assert_eq!(contract.get_season(), None);
+        }
 
-            // Calculate expected distributions (5% admin fee)
-            let admin_fee = 10000 * 5 / 100; // 500
-            let winner_pool = 10000 - admin_fee; // 9500
-            let first_prize = winner_pool * 50 / 100; // 4750
-            let second_prize = winner_pool * 30 / 100; // 2850
-            let third_prize = winner_pool * 20 / 100; // 1900
+        /// Test the season pool only grows while the season runs and unpaid funds carry over
+        #[ink::test]
+        fn season_pool_carries_over() {
+            let mut contract = AgarioBuyin::new(10).unwrap();
+            let player1 = H160::from([1; 20]);
 
-            // Note: In a real test environment, we'd verify the actual transfers
-            // For now, we verify the function completed successfully
+            let settle = |contract: &mut AgarioBuyin, timestamp: Timestamp| {
+                ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(timestamp);
+                contract.start_game(1000, 5, 2, None).unwrap();
+                seat_players(contract, &[player1]);
+                contract.game_state = GameState::WaitingForResults;
+                contract.prize_pool = 10000;
+                contract
+                    .submit_winners(vec![player1], vec![0], GameEndReason::TimeLimit)
+                    .unwrap();
+            };
+
+            // Only games settled between start and end fund the season
+            contract
+                .start_season(1000, 2000, vec![10], 5, vec![50])
+                .unwrap();
+            settle(&mut contract, 500);
+            assert_eq!(contract.get_season().unwrap().pool, 0);
+            settle(&mut contract, 1500);
+            assert_eq!(contract.get_season().unwrap().pool, 500);
+            settle(&mut contract, 2000);
+            assert_eq!(contract.get_season().unwrap().pool, 500);
+
+            // Half is paid, the rest carries over to the next season
+            contract.end_season().unwrap();
+            assert_eq!(contract.get_claimable(player1), 250);
+            assert_eq!(contract.get_season_carryover(), 250);
+            contract
+                .start_season(3000, 4000, vec![10], 5, vec![100])
+                .unwrap();
+            assert_eq!(contract.get_season().unwrap().pool, 250);
+            assert_eq!(contract.get_season_carryover(), 0);
+
+            // A season nobody scored in carries its whole pool over
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(4000);
+            contract.end_season().unwrap();
+            assert_eq!(contract.get_season_carryover(), 250);
         }
 
+        /// Test lifetime statistics across a settled and a refunded game
         #[ink::test]
-        fn submit_winners_handles_partial_percentages() {
-            let mut contract = AgarioBuyin::new(5).unwrap();
+        fn player_stats_are_tracked() {
+            let mut contract = AgarioBuyin::new(10).unwrap();
+            let admin = contract.get_admin();
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
 
-            // Setup game
-            let _ = contract.start_game(1000, 5, 2, Some(60));
+            // Settled game
+            contract.start_game(1000, 5, 2, None).unwrap();
+            deposit_as(&mut contract, player1, 1000).unwrap();
+            deposit_as(&mut contract, player2, 1000).unwrap();
+            ink::env::test::set_caller(admin);
             contract.game_state = GameState::WaitingForResults;
-            contract.prize_pool = 10000;
-
-            // Only distribute 80% of winnings, 20% stays in contract
-            let winners = vec![H160::from([1; 20]), H160::from([2; 20])];
-            let percentages = vec![50, 30]; // Total 80%
+            contract
+                .submit_winners(
+                    vec![player1, player2],
+                    vec![50, 50],
+                    GameEndReason::TimeLimit,
+                )
+                .unwrap();
+
+            // Refunded game
+            contract.start_game(500, 5, 3, None).unwrap();
+            deposit_as(&mut contract, player1, 500).unwrap();
+            ink::env::test::set_caller(admin);
+            contract.force_end_game().unwrap();
+            assert_eq!(contract.get_claimable(player1), 500);
+
+            let stats = contract.get_player_stats(player1);
+            assert_eq!(stats.games_played, 2);
+            assert_eq!(stats.wins, 1);
+            assert_eq!(stats.total_buy_ins, 1500);
+            assert_eq!(stats.total_winnings, 900);
+            assert_eq!(stats.refunds, 1);
+            assert_eq!(stats.total_refunded, 500);
+
+            let stats = contract.get_player_stats(player2);
+            assert_eq!(stats.wins, 0);
+            assert_eq!(stats.total_winnings, 900);
+            assert_eq!(
+                contract.get_player_stats(H160::from([9; 20])),
+                PlayerStats::default()
+            );
+        }
 
-            let result =
-                contract.submit_winners(winners, percentages, GameEndReason::LastPlayerStanding);
-            assert!(result.is_ok());
-            assert_eq!(contract.game_state, GameState::Inactive);
+        /// Test the fixed-point expected score approximation
+        #[ink::test]
+        fn expected_score_is_symmetric() {
+            assert_eq!(expected_score(0), 5000);
+            assert_eq!(expected_score(400), 9091);
+            assert_eq!(expected_score(-400), 909);
+            assert_eq!(expected_score(25), 5357);
+            assert_eq!(expected_score(5000), 9901);
+            assert_eq!(expected_score(120) + expected_score(-120), SCORE_SCALE);
         }
 
+        /// Test ratings update from placements and gate entry by rating band
         #[ink::test]
-        fn submit_winners_enforces_admin_only() {
+        fn ratings_update_and_band_is_enforced() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let admin = contract.get_admin();
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let player3 = H160::from([3; 20]);
 
-            // Setup game in WaitingForResults state
+            contract.start_game(1000, 5, 2, None).unwrap();
+            for player in [player1, player2, player3] {
+                deposit_as(&mut contract, player, 1000).unwrap();
+            }
+            ink::env::test::set_caller(admin);
             contract.game_state = GameState::WaitingForResults;
-            contract.prize_pool = 10000;
+            contract
+                .submit_winners(vec![player1], vec![100], GameEndReason::TimeLimit)
+                .unwrap();
+
+            // Winner beats both, the others draw with each other
+            assert_eq!(contract.get_rating(player1), 1516);
+            assert_eq!(contract.get_rating(player2), 1492);
+            assert_eq!(contract.get_rating(player3), 1492);
+            assert_eq!(contract.get_rating(H160::from([9; 20])), DEFAULT_RATING);
+
+            // Band excludes the lower rated players
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                registration_period: 5,
+                rating_band: Some((1500, 2000)),
+                ..Default::default()
+            };
+            contract.start_game_with_config(config).unwrap();
+            let result = deposit_as(&mut contract, player2, 1000);
+            assert!(matches!(result, Err(Error::RatingOutOfBand)));
+            deposit_as(&mut contract, player1, 1000).unwrap();
+        }
 
-            // Change caller to non-admin (default test caller is admin)
-            ink::env::test::set_caller(H160::from([99; 20]));
+        /// Test ratings move against the average rating of the field
+        #[ink::test]
+        fn ratings_compare_against_field_average() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let admin = contract.get_admin();
+            let players: Vec<H160> = (1..=4).map(|i| H160::from([i; 20])).collect();
 
-            let winners = vec![H160::from([1; 20])];
-            let percentages = vec![100];
+            contract.start_game(1000, 5, 2, None).unwrap();
+            for (player, rating) in players.iter().zip([1700, 1500, 1500, 1300]) {
+                deposit_as(&mut contract, *player, 1000).unwrap();
+                contract.ratings.insert(*player, &rating);
+            }
+            ink::env::test::set_caller(admin);
+            contract.game_state = GameState::WaitingForResults;
+            contract
+                .submit_winners(
+                    vec![players[3], players[0]],
+                    vec![70, 30],
+                    GameEndReason::TimeLimit,
+                )
+                .unwrap();
+
+            // The underdog gains most, unplaced players share the last rank
+            let ratings: Vec<u32> = players
+                .iter()
+                .map(|player| contract.get_rating(*player))
+                .collect();
+            assert_eq!(ratings, vec![1696, 1490, 1490, 1326]);
+        }
 
-            let result = contract.submit_winners(winners, percentages, GameEndReason::TimeLimit);
-            assert!(matches!(result, Err(Error::NotAdmin)));
+        /// Join the matchmaking queue with `stake` as `player`
+        fn join_queue_as(contract: &mut AgarioBuyin, player: H160, stake: Balance) -> Result<()> {
+            ink::env::test::set_caller(player);
+            set_value(stake);
+            contract.join_queue()
         }
 
+        /// Test the queue forms a game once enough players wait
         #[ink::test]
-        fn submit_winners_requires_correct_game_state() {
+        fn queue_forms_game() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let player3 = H160::from([3; 20]);
 
-            // Test various wrong states
-            let winners = vec![H160::from([1; 20])];
-            let percentages = vec![100];
-
-            // Test Inactive state
-            contract.game_state = GameState::Inactive;
-            let result = contract.submit_winners(
-                winners.clone(),
-                percentages.clone(),
-                GameEndReason::TimeLimit,
-            );
-            assert!(matches!(result, Err(Error::GameNotInCorrectState)));
-
-            // Test AcceptingDeposits state
-            contract.game_state = GameState::AcceptingDeposits;
-            let result = contract.submit_winners(
-                winners.clone(),
-                percentages.clone(),
-                GameEndReason::TimeLimit,
-            );
-            assert!(matches!(result, Err(Error::GameNotInCorrectState)));
+            contract
+                .set_queue_tier(
+                    1000,
+                    Some(QueueTier {
+                        players_per_game: 3,
+                        ..Default::default()
+                    }),
+                )
+                .unwrap();
+
+            // Only configured stakes can be queued for
+            let result = join_queue_as(&mut contract, player1, 500);
+            assert!(matches!(result, Err(Error::NoQueueTier)));
+
+            join_queue_as(&mut contract, player1, 1000).unwrap();
+            join_queue_as(&mut contract, player2, 1000).unwrap();
+            let result = join_queue_as(&mut contract, player2, 1000);
+            assert!(matches!(result, Err(Error::AlreadyQueued)));
+            assert_eq!(contract.get_queue_size(1000), 2);
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
 
-            // Test InProgress state
-            contract.game_state = GameState::InProgress;
-            let result = contract.submit_winners(
-                winners.clone(),
-                percentages.clone(),
-                GameEndReason::TimeLimit,
-            );
-            assert!(matches!(result, Err(Error::GameNotInCorrectState)));
+            // Leaving before a match refunds the stake
+            ink::env::test::set_caller(player2);
+            contract.leave_queue().unwrap();
+            assert_eq!(contract.get_queued_stake(player2), None);
+            assert!(matches!(contract.leave_queue(), Err(Error::NotQueued)));
 
-            // Only WaitingForResults should work
-            contract.game_state = GameState::WaitingForResults;
-            contract.prize_pool = 1000;
-            let result = contract.submit_winners(winners, percentages, GameEndReason::TimeLimit);
-            assert!(result.is_ok());
+            // Rejoining puts the player at the back; the third player completes the game
+            join_queue_as(&mut contract, player2, 1000).unwrap();
+            join_queue_as(&mut contract, player3, 1000).unwrap();
+            assert_eq!(contract.get_game_state(), GameState::InProgress);
+            assert_eq!(contract.get_player_count(), 3);
+            assert_eq!(contract.get_prize_pool(), 3000);
+            assert!(contract.is_player_registered(player2));
+            assert_eq!(contract.get_queue_size(1000), 0);
         }
 
-        /// 🎯 MVP CRITICAL TEST: Complete happy path flow (start → deposit → end)
-        /// This test simulates the exact flow that will be demonstrated in the hackathon
+        /// Test the queue only seats players within the tier's rating width
         #[ink::test]
-        fn mvp_complete_happy_path_flow() {
+        fn queue_respects_rating_width() {
             let mut contract = AgarioBuyin::new(5).unwrap();
-
-            // STEP 1: Admin starts game (Demo Step 1)
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let strong_player = H160::from([3; 20]);
+            contract.ratings.insert(strong_player, &1800);
+
+            contract
+                .set_queue_tier(
+                    1000,
+                    Some(QueueTier {
+                        players_per_game: 2,
+                        rating_width: Some(100),
+                        game_duration: None,
+                    }),
+                )
+                .unwrap();
+
+            join_queue_as(&mut contract, player1, 1000).unwrap();
+            join_queue_as(&mut contract, strong_player, 1000).unwrap();
             assert_eq!(contract.get_game_state(), GameState::Inactive);
-            let result = contract.start_game(10000, 10, 2, Some(30)); // 10,000 units buy-in, 10min reg, 2 min players, 30min duration
-            assert!(result.is_ok());
-            assert_eq!(contract.get_game_state(), GameState::AcceptingDeposits);
-            assert_eq!(contract.get_buy_in_amount(), 10000);
-            assert_eq!(contract.get_min_players(), 2);
 
-            // STEP 2: Players join game (Demo Step 2)
-            // Simulate multiple players depositing
-            // Note: In real demo, we'd use payable calls with actual DOT transfers
+            join_queue_as(&mut contract, player2, 1000).unwrap();
+            assert_eq!(contract.get_game_state(), GameState::InProgress);
+            assert!(contract.is_player_registered(player1));
+            assert!(contract.is_player_registered(player2));
+            assert_eq!(contract.get_queued_stake(strong_player), Some(1000));
+        }
 
-            // Manually simulate player registration for MVP testing
-            let player1 = H160::from([1; 20]);
+        /// Test players leaving the queue do not leave stale entries blocking later matches
+        #[ink::test]
+        fn queue_matches_after_many_leaves() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let churner = H160::from([1; 20]);
             let player2 = H160::from([2; 20]);
             let player3 = H160::from([3; 20]);
 
-            // Simulate player deposits by manually updating state (for unit test)
-            contract.players.insert(player1, &());
-            contract.player_count = 1;
-            contract.prize_pool = 10000; // Player 1 deposit
+            contract
+                .set_queue_tier(
+                    1000,
+                    Some(QueueTier {
+                        players_per_game: 2,
+                        ..Default::default()
+                    }),
+                )
+                .unwrap();
+
+            for _ in 0..QUEUE_SCAN_LIMIT + 6 {
+                join_queue_as(&mut contract, churner, 1000).unwrap();
+                contract.leave_queue().unwrap();
+            }
+            assert_eq!(contract.get_queue_size(1000), 0);
 
-            contract.players.insert(player2, &());
-            contract.player_count = 2;
-            contract.prize_pool = 20000; // Player 1 + 2 deposits
+            join_queue_as(&mut contract, player2, 1000).unwrap();
+            join_queue_as(&mut contract, player3, 1000).unwrap();
+            assert_eq!(contract.get_game_state(), GameState::InProgress);
+            assert!(contract.is_player_registered(player2));
+            assert!(contract.is_player_registered(player3));
+        }
 
-            contract.players.insert(player3, &());
-            contract.player_count = 3;
-            contract.prize_pool = 30000; // Player 1 + 2 + 3 deposits
+        /// Test an out of range player at the head of the queue does not block matches
+        #[ink::test]
+        fn queue_matches_behind_out_of_range_head() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let strong_player = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let player3 = H160::from([3; 20]);
+            contract.ratings.insert(strong_player, &2000);
+            contract.ratings.insert(player3, &1550);
+
+            contract
+                .set_queue_tier(
+                    1000,
+                    Some(QueueTier {
+                        players_per_game: 2,
+                        rating_width: Some(100),
+                        game_duration: None,
+                    }),
+                )
+                .unwrap();
+
+            join_queue_as(&mut contract, strong_player, 1000).unwrap();
+            join_queue_as(&mut contract, player2, 1000).unwrap();
+            assert_eq!(contract.get_game_state(), GameState::Inactive);
 
-            // Verify player registrations
-            assert!(contract.is_player_registered(player1));
+            // The next player anchors a window of its own
+            join_queue_as(&mut contract, player3, 1000).unwrap();
+            assert_eq!(contract.get_game_state(), GameState::InProgress);
             assert!(contract.is_player_registered(player2));
             assert!(contract.is_player_registered(player3));
-            assert_eq!(contract.get_player_count(), 3);
-            assert_eq!(contract.get_prize_pool(), 30000);
+            assert_eq!(contract.get_queued_stake(strong_player), Some(1000));
+            assert_eq!(contract.get_queue_size(1000), 1);
+        }
 
-            // STEP 3: Game begins automatically when conditions met
-            // Simulate time passing and try to begin game
-            contract.registration_deadline = 0; // Simulate deadline passed
-            let result = contract.try_begin_game();
-            assert!(result.is_ok());
-            assert_eq!(contract.get_game_state(), GameState::InProgress);
+        /// Test allowlist, Merkle and passcode entry gates
+        #[ink::test]
+        fn entry_gates_work() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let outsider = H160::from([3; 20]);
+            let admin = contract.get_admin();
+            let gated_config = |entry_gate| GameConfig {
+                buy_in: 1000,
+                min_players: 3,
+                registration_period: 5,
+                entry_gate,
+                ..Default::default()
+            };
+
+            // Admin-managed allowlist of the next round
+            let round = contract.get_current_round() + 1;
+            contract
+                .set_allowlisted(round, vec![player1], true)
+                .unwrap();
+            contract
+                .start_game_with_config(gated_config(EntryGate::Allowlist))
+                .unwrap();
+            deposit_as(&mut contract, player1, 1000).unwrap();
+            let result = deposit_as(&mut contract, outsider, 1000);
+            assert!(matches!(result, Err(Error::NotAllowlisted)));
+            ink::env::test::set_caller(admin);
+            contract.force_end_game().unwrap();
+
+            // The allowlist does not carry over to the next game
+            contract
+                .start_game_with_config(gated_config(EntryGate::Allowlist))
+                .unwrap();
+            let result = deposit_as(&mut contract, player1, 1000);
+            assert!(matches!(result, Err(Error::NotAllowlisted)));
+            ink::env::test::set_caller(admin);
+            assert!(matches!(
+                contract.set_allowlisted(round, vec![player1], true),
+                Err(Error::InvalidRound)
+            ));
+            contract.force_end_game().unwrap();
+
+            // Merkle root over player1 and player2
+            let leaf1 = keccak256(player1.as_bytes());
+            let leaf2 = keccak256(player2.as_bytes());
+            let root = hash_pair(&leaf1, &leaf2);
+            contract
+                .start_game_with_config(gated_config(EntryGate::MerkleRoot(root)))
+                .unwrap();
+            ink::env::test::set_caller(player2);
+            set_value(1000);
+            contract
+                .deposit_with_credential(None, EntryCredential::MerkleProof(vec![leaf1]))
+                .unwrap();
+            ink::env::test::set_caller(outsider);
+            let result =
+                contract.deposit_with_credential(None, EntryCredential::MerkleProof(vec![leaf1]));
+            assert!(matches!(result, Err(Error::InvalidMerkleProof)));
+            ink::env::test::set_caller(admin);
+            contract.force_end_game().unwrap();
+
+            // Passcode
+            let hash = keccak256(b"let me in");
+            contract
+                .start_game_with_config(gated_config(EntryGate::Passcode(hash)))
+                .unwrap();
+            ink::env::test::set_caller(outsider);
+            set_value(1000);
+            let result = contract
+                .deposit_with_credential(None, EntryCredential::Passcode(b"wrong".to_vec()));
+            assert!(matches!(result, Err(Error::InvalidPasscode)));
+            assert!(matches!(contract.deposit(), Err(Error::InvalidPasscode)));
+            contract
+                .deposit_with_credential(None, EntryCredential::Passcode(b"let me in".to_vec()))
+                .unwrap();
+            assert!(contract.is_player_registered(outsider));
+        }
 
-            // STEP 4: Game ends and transitions to WaitingForResults
-            let result = contract.report_game_end(GameEndReason::LastPlayerStanding);
-            assert!(result.is_ok());
-            assert_eq!(contract.get_game_state(), GameState::WaitingForResults);
+        /// Test referrers are recorded once and earn a share of the admin fee
+        #[ink::test]
+        fn referrals_share_admin_fee() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let streamer = H160::from([8; 20]);
+            let other_streamer = H160::from([9; 20]);
 
-            // STEP 5: Admin submits winners and distributes prizes (Demo Step 3)
-            let winners = vec![player1, player2]; // 1st and 2nd place
-            let percentages = vec![60, 40]; // 60% to winner, 40% to second place
+            contract.set_referral_percentage(50).unwrap();
+            contract.start_game(1000, 5, 2, None).unwrap();
 
-            let result = contract.submit_winners(
-                winners.clone(),
-                percentages.clone(),
-                GameEndReason::LastPlayerStanding,
-            );
-            assert!(result.is_ok());
+            ink::env::test::set_caller(player1);
+            set_value(1000);
+            contract.deposit_with_referrer(streamer).unwrap();
+            deposit_as(&mut contract, player2, 1000).unwrap();
+            assert_eq!(contract.get_referrer(player1), Some(streamer));
+            assert_eq!(contract.get_referrer(player2), None);
+            assert_eq!(contract.get_referral_count(streamer), 1);
 
-            // STEP 6: Verify game reset for next round (Demo Step 4)
-            assert_eq!(contract.get_game_state(), GameState::Inactive);
-            assert_eq!(contract.get_player_count(), 0);
-            assert_eq!(contract.get_prize_pool(), 0);
-            assert_eq!(contract.get_buy_in_amount(), 0);
+            // Admin fee is 100, player1's slice is 50 and the streamer gets half of it
+            contract.game_state = GameState::WaitingForResults;
+            ink::env::test::set_caller(contract.get_admin());
+            contract
+                .submit_winners(vec![player2], vec![100], GameEndReason::TimeLimit)
+                .unwrap();
+            assert_eq!(contract.get_claimable(streamer), 25);
+            assert_eq!(contract.get_referral_earnings(streamer), 25);
+
+            // The first referrer is kept
+            contract.start_game(1000, 5, 2, None).unwrap();
+            ink::env::test::set_caller(player1);
+            set_value(1000);
+            contract.deposit_with_referrer(other_streamer).unwrap();
+            assert_eq!(contract.get_referrer(player1), Some(streamer));
+            assert_eq!(contract.get_referral_count(other_streamer), 0);
+        }
 
-            // Prize distribution verification:
-            // Total pool: 30,000 units
-            // Admin fee (5%): 1,500 units
-            // Winner pool: 28,500 units
-            // 1st place (60%): 17,100 units
-            // 2nd place (40%): 11,400 units
-            // Note: Actual transfer verification would be done in E2E tests
+        /// Test the fee on sponsor money is not shared with referrers
+        #[ink::test]
+        fn referral_shares_exclude_sponsor_money() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let sponsor = H160::from([70; 20]);
+            let streamer = H160::from([8; 20]);
+
+            contract.set_referral_percentage(50).unwrap();
+            contract.start_game(1000, 5, 2, None).unwrap();
+            ink::env::test::set_caller(player1);
+            set_value(1000);
+            contract.deposit_with_referrer(streamer).unwrap();
+            deposit_as(&mut contract, player2, 1000).unwrap();
+            ink::env::test::set_caller(sponsor);
+            set_value(2000);
+            contract.sponsor_pool(contract.get_current_round()).unwrap();
+
+            // Admin fee is 200 on a 4000 pool, player1's slice is a quarter of it
+            contract.game_state = GameState::WaitingForResults;
+            ink::env::test::set_caller(contract.get_admin());
+            contract
+                .submit_winners(vec![player2], vec![100], GameEndReason::TimeLimit)
+                .unwrap();
+            assert_eq!(contract.get_referral_earnings(streamer), 25);
         }
 
-        /// 🎯 MVP TEST: Admin access control enforcement
+        /// Test a buy-in paid for another player seats them and refunds the payer
         #[ink::test]
-        fn mvp_admin_access_control_comprehensive() {
+        fn deposit_for_refunds_payer() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let payer = H160::from([1; 20]);
+            let player = H160::from([2; 20]);
+
+            contract.start_game(1000, 5, 2, None).unwrap();
+            ink::env::test::set_caller(payer);
+            set_value(1000);
+            contract.deposit_for(player).unwrap();
+
+            assert!(contract.is_player_registered(player));
+            assert!(!contract.is_player_registered(payer));
+            assert_eq!(contract.get_player_info(player).unwrap().payer, Some(payer));
+
+            // A second buy-in for the same player is rejected
+            let result = contract.deposit_for(player);
+            assert!(matches!(result, Err(Error::PlayerAlreadyDeposited)));
+
+            ink::env::test::set_caller(contract.get_admin());
+            contract.force_end_game().unwrap();
+            assert_eq!(contract.get_claimable(payer), 1000);
+            assert_eq!(contract.get_claimable(player), 0);
+            assert_eq!(contract.get_player_stats(payer).total_refunded, 1000);
+            assert_eq!(contract.get_player_stats(player).refunds, 0);
+        }
 
-            // Mock non-admin caller
-            let non_admin_address = H160::from([99; 20]);
+        /// Test seats can be given away or sold before the game begins
+        #[ink::test]
+        fn seats_can_be_transferred() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let holder = H160::from([1; 20]);
+            let friend = H160::from([2; 20]);
+            let buyer = H160::from([3; 20]);
+            let outsider = H160::from([4; 20]);
+
+            contract
+                .set_allowlisted(
+                    contract.get_current_round() + 1,
+                    vec![holder, friend, buyer],
+                    true,
+                )
+                .unwrap();
+            contract
+                .start_game_with_config(GameConfig {
+                    buy_in: 1000,
+                    min_players: 3,
+                    registration_period: 5,
+                    entry_gate: EntryGate::Allowlist,
+                    ..Default::default()
+                })
+                .unwrap();
+            deposit_as(&mut contract, holder, 1000).unwrap();
+
+            // The allowlist applies to the new holder
+            let result = contract.transfer_seat(outsider);
+            assert!(matches!(result, Err(Error::NotAllowlisted)));
+
+            contract.transfer_seat(friend).unwrap();
+            assert!(!contract.is_player_registered(holder));
+            assert!(contract.is_player_registered(friend));
+            assert_eq!(contract.get_player_count(), 1);
+            assert_eq!(contract.get_player_stats(friend).games_played, 1);
+            assert_eq!(contract.get_player_stats(holder).games_played, 0);
+
+            // Sell the seat on through the contract
+            ink::env::test::set_caller(friend);
+            contract.offer_seat(buyer, 700).unwrap();
+            ink::env::test::set_caller(buyer);
+            set_value(500);
+            let result = contract.accept_seat(friend, None);
+            assert!(matches!(result, Err(Error::IncorrectPayment)));
+            set_value(700);
+            contract.accept_seat(friend, None).unwrap();
+            assert!(contract.is_player_registered(buyer));
+            assert!(!contract.is_player_registered(friend));
+            assert_eq!(contract.get_seat_offer(friend), None);
+            assert_eq!(contract.get_claimable(friend), 700);
+
+            // A refund goes to the current holder
+            ink::env::test::set_caller(contract.get_admin());
+            contract.force_end_game().unwrap();
+            assert_eq!(contract.get_claimable(buyer), 1000);
+        }
 
-            // Test 1: start_game requires admin
-            ink::env::test::set_caller(non_admin_address);
-            let result = contract.start_game(1000, 10, 2, Some(30));
-            assert!(matches!(result, Err(Error::NotAdmin)));
+        /// Test a transferred seat no longer refunds its payer or pays its referrer
+        #[ink::test]
+        fn seat_transfer_keeps_payer_and_drops_referral() {
+            let mut contract = AgarioBuyin::new(10).unwrap();
+            let admin = contract.get_admin();
+            let sponsor = H160::from([1; 20]);
+            let holder = H160::from([2; 20]);
+            let friend = H160::from([3; 20]);
+            let referrer = H160::from([4; 20]);
+            let player = H160::from([5; 20]);
+
+            contract.set_referral_percentage(50).unwrap();
+            contract.start_game(1000, 5, 2, None).unwrap();
+            ink::env::test::set_caller(sponsor);
+            set_value(1000);
+            contract.deposit_for(holder).unwrap();
+            ink::env::test::set_caller(holder);
+            assert_eq!(contract.transfer_seat(friend), Err(Error::NotAuthorized));
+            assert_eq!(contract.offer_seat(friend, 500), Err(Error::NotAuthorized));
+            assert_eq!(
+                contract.get_player_info(holder).unwrap().payer,
+                Some(sponsor)
+            );
 
-            // Reset to admin caller
-            ink::env::test::set_caller(contract.game_admin);
-            contract.start_game(1000, 10, 2, Some(30)).unwrap();
+            // The payer keeps the refund of the seat they funded
+            ink::env::test::set_caller(admin);
+            contract.force_end_game().unwrap();
+            assert_eq!(contract.get_claimable(sponsor), 1000);
+            assert_eq!(contract.get_claimable(holder), 0);
+            assert!(!contract.is_player_registered(friend));
+
+            // A referred seat stops earning its referrer once transferred
+            contract.start_game(1000, 5, 2, None).unwrap();
+            ink::env::test::set_caller(player);
+            set_value(1000);
+            contract.deposit_with_referrer(referrer).unwrap();
+            contract.transfer_seat(holder).unwrap();
+            assert_eq!(contract.get_player_info(holder).unwrap().referrer, None);
+            deposit_as(&mut contract, friend, 1000).unwrap();
+            ink::env::test::set_caller(admin);
             contract.game_state = GameState::WaitingForResults;
-            contract.prize_pool = 1000;
-
-            // Test 2: submit_winners requires admin
-            ink::env::test::set_caller(non_admin_address);
-            let winners = vec![H160::from([1; 20])];
-            let percentages = vec![100];
-            let result = contract.submit_winners(winners, percentages, GameEndReason::TimeLimit);
-            assert!(matches!(result, Err(Error::NotAdmin)));
-
-            // Test 3: force_end_game requires admin
-            let result = contract.force_end_game();
-            assert!(matches!(result, Err(Error::NotAdmin)));
-
-            // Reset to admin and verify functions work
-            ink::env::test::set_caller(contract.game_admin);
-            let winners = vec![H160::from([1; 20])];
-            let percentages = vec![100];
-            let result = contract.submit_winners(winners, percentages, GameEndReason::TimeLimit);
-            assert!(result.is_ok());
+            contract
+                .submit_winners(vec![friend], vec![100], GameEndReason::TimeLimit)
+                .unwrap();
+            assert_eq!(contract.get_referral_earnings(referrer), 0);
         }
 
-        /// 🎯 MVP TEST: Critical error cases for demo robustness
+        /// Test token games take no native value and reject treasury-backed features
         #[ink::test]
-        fn mvp_critical_error_cases() {
+        fn token_games_validate_currency() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let token = H160::from([7; 20]);
+            let player = H160::from([1; 20]);
+            let token_config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                registration_period: 5,
+                currency: Currency::Token(token),
+                ..Default::default()
+            };
+
+            let result = contract.start_game_with_config(GameConfig {
+                guaranteed_pool: Some(5000),
+                ..token_config.clone()
+            });
+            assert!(matches!(result, Err(Error::UnsupportedCurrency)));
+
+            contract.start_game_with_config(token_config).unwrap();
+            assert_eq!(contract.get_currency(), Currency::Token(token));
+
+            // The buy-in is pulled from the token, not sent along with the call
+            let result = deposit_as(&mut contract, player, 1000);
+            assert!(matches!(result, Err(Error::IncorrectBuyInAmount)));
+
+            ink::env::test::set_caller(player);
+            set_value(1000);
+            let result = contract.sponsor_pool(contract.get_current_round());
+            assert!(matches!(result, Err(Error::UnsupportedCurrency)));
+        }
 
-            // Error Case 1: Starting game with invalid parameters
-            let result = contract.start_game(1000, 10, 1, Some(30)); // < 2 min players
-            assert!(matches!(result, Err(Error::TooFewPlayers)));
+        /// Test seat tickets are minted, move the seat when transferred and burn at the end
+        #[ink::test]
+        fn tickets_represent_seats() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let marketplace = H160::from([3; 20]);
+            let buyer = H160::from([4; 20]);
+
+            contract.start_game(1000, 5, 2, None).unwrap();
+            deposit_as(&mut contract, player1, 1000).unwrap();
+            let ticket = contract.get_player_info(player1).unwrap().ticket.unwrap();
+            assert_eq!(contract.ticket_owner_of(ticket), Some(player1));
+            assert_eq!(contract.ticket_balance_of(player1), 1);
+
+            // An approved marketplace moves the ticket and the seat follows
+            let result = contract.approve_ticket(marketplace, ticket + 1);
+            assert!(matches!(result, Err(Error::TicketNotFound)));
+            contract.approve_ticket(marketplace, ticket).unwrap();
+            ink::env::test::set_caller(marketplace);
+            contract
+                .transfer_ticket_from(player1, buyer, ticket)
+                .unwrap();
+            assert_eq!(contract.ticket_owner_of(ticket), Some(buyer));
+            assert_eq!(contract.get_ticket_approved(ticket), None);
+            assert!(contract.is_player_registered(buyer));
+            assert!(!contract.is_player_registered(player1));
+
+            // Tickets are burned at settlement
+            deposit_as(&mut contract, player2, 1000).unwrap();
+            contract.game_state = GameState::WaitingForResults;
+            ink::env::test::set_caller(contract.get_admin());
+            contract
+                .submit_winners(vec![buyer], vec![100], GameEndReason::TimeLimit)
+                .unwrap();
+            assert_eq!(contract.ticket_owner_of(ticket), None);
+            assert_eq!(contract.ticket_balance_of(buyer), 0);
+
+            // Operators are approved for all tickets, but not the owner itself
+            ink::env::test::set_caller(player2);
+            let result = contract.set_ticket_approval_for_all(player2, true);
+            assert!(matches!(result, Err(Error::SelfApproval)));
+            contract
+                .set_ticket_approval_for_all(marketplace, true)
+                .unwrap();
+            assert!(contract.is_ticket_approved_for_all(player2, marketplace));
+        }
 
-            // Error Case 2: Operations in wrong game state
-            let winners = vec![H160::from([1; 20])];
-            let percentages = vec![100];
-            let result = contract.submit_winners(
-                winners.clone(),
-                percentages.clone(),
-                GameEndReason::TimeLimit,
-            );
-            assert!(matches!(result, Err(Error::GameNotInCorrectState))); // Game not started
+        /// Test trophies go to top placements and badges follow the winner's statistics
+        #[ink::test]
+        fn trophies_and_badges_are_awarded() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let player3 = H160::from([3; 20]);
 
-            // Error Case 3: Invalid winner data
-            contract.start_game(1000, 10, 2, Some(30)).unwrap();
+            contract.set_trophy_places(2).unwrap();
+            contract.start_game(1000, 5, 2, None).unwrap();
+            seat_players(&mut contract, &[player1, player2, player3]);
             contract.game_state = GameState::WaitingForResults;
-            contract.prize_pool = 1000;
-
-            // Empty winners
-            let result = contract.submit_winners(vec![], vec![], GameEndReason::TimeLimit);
-            assert!(matches!(result, Err(Error::NoWinners)));
+            contract.prize_pool = 10000;
+            contract
+                .submit_winners(
+                    vec![player1, player2, player3],
+                    vec![50, 30, 20],
+                    GameEndReason::LastPlayerStanding,
+                )
+                .unwrap();
+
+            assert_eq!(
+                contract.get_player_trophies(player2),
+                vec![Trophy {
+                    owner: player2,
+                    round: 1,
+                    placement: 2,
+                    pool: 10000,
+                    reason: GameEndReason::LastPlayerStanding,
+                }]
+            );
+            assert_eq!(contract.get_trophy_count_of(player3), 0);
+            assert_eq!(contract.get_badge(player1, Badge::FirstWin), Some(1));
+            assert_eq!(contract.get_badge(player1, Badge::BiggestPool), Some(1));
+            assert_eq!(contract.get_badge(player1, Badge::TenWins), None);
+            assert_eq!(contract.get_badge(player2, Badge::FirstWin), None);
+        }
 
-            // Mismatched data
-            let result = contract.submit_winners(winners, vec![50, 30], GameEndReason::TimeLimit); // length mismatch
-            assert!(matches!(result, Err(Error::MismatchedData)));
+        /// Test pegged buy-in validation and slippage tolerance
+        #[ink::test]
+        fn pegged_buy_in_validates_config() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let peg = PeggedBuyIn {
+                oracle: H160::from([9; 20]),
+                reference_amount: 1000,
+                max_price_age: 60_000,
+                slippage_bps: Some(100),
+            };
+
+            let result = contract.start_game_with_config(GameConfig {
+                min_players: 2,
+                pegged_buy_in: Some(peg),
+                currency: Currency::Token(H160::from([7; 20])),
+                ..Default::default()
+            });
+            assert!(matches!(result, Err(Error::UnsupportedCurrency)));
+
+            let result = contract.start_game_with_config(GameConfig {
+                min_players: 2,
+                pegged_buy_in: Some(PeggedBuyIn {
+                    slippage_bps: Some(10_001),
+                    ..peg
+                }),
+                ..Default::default()
+            });
+            assert!(matches!(result, Err(Error::InvalidPeggedBuyIn)));
+
+            // 1% tolerance around a quote of 10000
+            assert!(within_tolerance(10_100, 10_000, 100));
+            assert!(within_tolerance(9_900, 10_000, 100));
+            assert!(!within_tolerance(10_101, 10_000, 100));
+        }
 
-            // Invalid percentages (> 100%)
-            let winners = vec![H160::from([1; 20]), H160::from([2; 20])];
-            let percentages = vec![60, 50]; // 110% total
-            let result = contract.submit_winners(winners, percentages, GameEndReason::TimeLimit);
-            assert!(matches!(result, Err(Error::InvalidPercentages)));
+        /// Test pegged buy-ins convert fresh prices and reject broken or stale ones
+        #[ink::test]
+        fn pegged_amount_checks_price() {
+            let peg = PeggedBuyIn {
+                oracle: H160::from([40; 20]),
+                reference_amount: 20,
+                max_price_age: 60_000,
+                slippage_bps: None,
+            };
+
+            assert_eq!(pegged_amount(&peg, (500, 100_000), 160_000), Ok(10_000));
+            assert_eq!(
+                pegged_amount(&peg, (500, 100_000), 160_001),
+                Err(Error::StalePrice)
+            );
+            assert_eq!(
+                pegged_amount(&peg, (0, 100_000), 100_000),
+                Err(Error::InvalidPrice)
+            );
+            assert_eq!(
+                pegged_amount(&peg, (500, 100_001), 100_000),
+                Err(Error::InvalidPrice)
+            );
+            assert_eq!(
+                pegged_amount(&peg, (u128::MAX, 100_000), 100_000),
+                Err(Error::InvalidPrice)
+            );
         }
 
-        /// 🎯 MVP TEST: Game timing and automatic transitions
+        /// Test entry fees are charged on top of the stake and returned on refund
         #[ink::test]
-        fn mvp_timing_and_transitions() {
+        fn entry_fee_is_separate_from_stake() {
             let mut contract = AgarioBuyin::new(5).unwrap();
+            let player1 = H160::from([1; 20]);
+            let player2 = H160::from([2; 20]);
+            let config = GameConfig {
+                buy_in: 1000,
+                entry_fee: 100,
+                min_players: 2,
+                registration_period: 5,
+                ..Default::default()
+            };
+
+            contract.start_game_with_config(config.clone()).unwrap();
+            let result = deposit_as(&mut contract, player1, 1000);
+            assert!(matches!(result, Err(Error::IncorrectBuyInAmount)));
+            deposit_as(&mut contract, player1, 1100).unwrap();
+            assert_eq!(contract.get_prize_pool(), 1000);
+            assert_eq!(contract.get_entry_fees(), 100);
+            assert_eq!(contract.get_player_info(player1).unwrap().entry_fee, 100);
+
+            // Refunds return stake and entry fee by default
+            ink::env::test::set_caller(contract.get_admin());
+            contract.force_end_game().unwrap();
+            assert_eq!(contract.get_claimable(player1), 1100);
+            assert_eq!(contract.get_total_entry_fees(), 0);
+
+            // At settlement the whole stake is paid out and the fee goes to the admin
+            contract.start_game_with_config(config).unwrap();
+            deposit_as(&mut contract, player1, 1100).unwrap();
+            deposit_as(&mut contract, player2, 1100).unwrap();
+            contract.game_state = GameState::WaitingForResults;
+            ink::env::test::set_caller(contract.get_admin());
+            contract
+                .submit_winners(vec![player2], vec![100], GameEndReason::TimeLimit)
+                .unwrap();
+            assert_eq!(contract.get_player_stats(player2).total_winnings, 2000);
+            assert_eq!(contract.get_total_entry_fees(), 200);
+        }
 
-            // Test automatic transition from registration to game start
-            contract.start_game(1000, 0, 2, Some(10)).unwrap(); // Immediate deadline
-            contract.player_count = 3; // Sufficient players
-            contract.registration_deadline = 0; // Past deadline
+        /// Test fees are split between beneficiaries and split changes wait for the next round
+        #[ink::test]
+        fn fee_split_credits_beneficiaries() {
+            let mut contract = AgarioBuyin::new(10).unwrap();
+            let winner = H160::from([1; 20]);
+            let treasury = H160::from([5; 20]);
+            let host = H160::from([6; 20]);
+            let dev_fund = H160::from([7; 20]);
+            let charity = H160::from([8; 20]);
+            let split = FeeSplit {
+                treasury,
+                treasury_bps: 5_000,
+                host,
+                host_bps: 3_000,
+                dev_fund,
+                dev_fund_bps: 1_500,
+                charity: Some(charity),
+                charity_bps: 500,
+            };
+
+            let result = contract.set_fee_split(Some(FeeSplit {
+                charity: None,
+                ..split
+            }));
+            assert!(matches!(result, Err(Error::InvalidFeeSplit)));
+
+            contract.set_fee_split(Some(split)).unwrap();
+            contract.start_game(1000, 5, 2, None).unwrap();
+
+            // Changing the split mid-round does not affect the running round
+            contract
+                .set_fee_split(Some(FeeSplit {
+                    treasury_bps: 10_000,
+                    host_bps: 0,
+                    dev_fund_bps: 0,
+                    charity_bps: 0,
+                    ..split
+                }))
+                .unwrap();
+            assert_eq!(contract.get_round_fee_split(), Some(split));
+
+            seat_players(&mut contract, &[winner]);
+            contract.game_state = GameState::WaitingForResults;
+            contract.prize_pool = 10000;
+            contract
+                .submit_winners(vec![winner], vec![100], GameEndReason::TimeLimit)
+                .unwrap();
+            assert_eq!(contract.get_claimable(treasury), 500);
+            assert_eq!(contract.get_claimable(host), 300);
+            assert_eq!(contract.get_claimable(dev_fund), 150);
+            assert_eq!(contract.get_claimable(charity), 50);
+        }
 
-            // Should auto-transition to InProgress
-            let result = contract.check_game_conditions();
-            assert!(result.is_ok());
-            assert_eq!(contract.get_game_state(), GameState::InProgress);
+        /// Test tournament tables keep the fee split captured at registration
+        #[ink::test]
+        fn tournament_keeps_fee_split() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let admin = contract.get_admin();
+            let treasury = H160::from([5; 20]);
+            let players: Vec<H160> = (1..=2).map(|i| H160::from([i; 20])).collect();
+            let split = FeeSplit {
+                treasury,
+                treasury_bps: 10_000,
+                ..Default::default()
+            };
+
+            contract.set_fee_split(Some(split)).unwrap();
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 2,
+                registration_period: 5,
+                ..Default::default()
+            };
+            contract.start_tournament(config, 2, 1, vec![100]).unwrap();
+            for player in players.iter() {
+                deposit_as(&mut contract, *player, 1000).unwrap();
+            }
+            ink::env::test::set_caller(admin);
+            contract.registration_deadline = 0;
+            contract.check_game_conditions().unwrap();
+
+            // Later split changes do not affect the running tournament
+            contract.set_fee_split(None).unwrap();
+            contract.start_tournament_table().unwrap();
+            assert_eq!(contract.get_round_fee_split(), Some(split));
+            contract.game_state = GameState::WaitingForResults;
+            contract
+                .submit_winners(vec![players[0]], vec![], GameEndReason::LastPlayerStanding)
+                .unwrap();
+            assert_eq!(contract.get_claimable(treasury), 100);
+        }
 
-            // Test game duration expiry
-            contract.game_start_time = 0;
-            contract.game_duration = Some(0); // Immediate expiry
+        /// Test fees accrue in the contract and only a treasurer can withdraw them
+        #[ink::test]
+        fn fees_accrue_for_treasurer() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
+            let admin = contract.get_admin();
+            let winner = H160::from([1; 20]);
+            let treasurer = H160::from([9; 20]);
 
-            // Should auto-transition to WaitingForResults
-            let result = contract.check_game_conditions();
-            assert!(result.is_ok());
-            assert_eq!(contract.get_game_state(), GameState::WaitingForResults);
+            contract.start_game(1000, 5, 2, None).unwrap();
+            seat_players(&mut contract, &[winner]);
+            contract.game_state = GameState::WaitingForResults;
+            contract.prize_pool = 10000;
+            contract
+                .submit_winners(vec![winner], vec![100], GameEndReason::TimeLimit)
+                .unwrap();
+            assert_eq!(contract.get_accrued_fees(Currency::Native), 500);
+            assert_eq!(contract.get_lifetime_fees(Currency::Native), 500);
+
+            // The admin is not a treasurer unless granted the role
+            let result = contract.withdraw_fees(admin, 100);
+            assert!(matches!(result, Err(Error::MissingRole)));
+            contract.grant_role(Role::Treasurer, treasurer).unwrap();
+
+            ink::env::test::set_caller(treasurer);
+            let result = contract.withdraw_fees(treasurer, 501);
+            assert!(matches!(result, Err(Error::InsufficientFees)));
+            contract.withdraw_fees(treasurer, 300).unwrap();
+            assert_eq!(contract.get_accrued_fees(Currency::Native), 200);
+            assert_eq!(contract.get_lifetime_fees(Currency::Native), 500);
+        }
 
-            // Test refund on insufficient players
-            let mut contract2 = AgarioBuyin::new(5).unwrap();
-            contract2.start_game(1000, 0, 5, Some(10)).unwrap(); // Need 5 players
-            contract2.player_count = 2; // Only 2 players
-            contract2.registration_deadline = 0; // Past deadline
+        /// Test start_game_with_config validates max players
+        #[ink::test]
+        fn start_game_with_config_validates_max_players() {
+            let mut contract = AgarioBuyin::new(5).unwrap();
 
-            // Should refund and reset
-            let result = contract2.check_game_conditions();
-            assert!(result.is_ok());
-            assert_eq!(contract2.get_game_state(), GameState::Inactive);
-            assert_eq!(contract2.player_count, 0);
+            let config = GameConfig {
+                buy_in: 1000,
+                min_players: 4,
+                max_players: Some(3),
+                ..Default::default()
+            };
+            let result = contract.start_game_with_config(config);
+            assert!(matches!(result, Err(Error::InvalidPlayerLimits)));
         }
     }
 
@@ -1242,5 +6995,156 @@ mod agario_buyin {
 
             Ok(())
         }
+
+        /// We test that a token game pulls the buy-in through the token's allowance and pays
+        /// refunds out in the token.
+        #[ink_e2e::test(additional_contracts = "../mock_token/Cargo.toml")]
+        async fn token_deposit_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            use mock_token::MockTokenRef;
+
+            // Given
+            let token_account_id = client
+                .instantiate(
+                    "mock_token",
+                    &ink_e2e::alice(),
+                    MockTokenRef::new(1_000_000),
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let contract_account_id = client
+                .instantiate(
+                    "agario_buyin",
+                    &ink_e2e::alice(),
+                    AgarioBuyinRef::new(5),
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let start_game =
+                build_message::<AgarioBuyinRef>(contract_account_id.clone()).call(|contract| {
+                    contract.start_game_with_config(GameConfig {
+                        buy_in: 1000,
+                        min_players: 2,
+                        registration_period: 5,
+                        currency: Currency::Token(token_account_id.clone()),
+                        ..Default::default()
+                    })
+                });
+            client
+                .call(&ink_e2e::alice(), start_game, 0, None)
+                .await
+                .expect("start_game_with_config failed");
+
+            // When
+            let approve = build_message::<MockTokenRef>(token_account_id.clone())
+                .call(|token| token.approve(contract_account_id.clone(), 1000));
+            client
+                .call(&ink_e2e::alice(), approve, 0, None)
+                .await
+                .expect("approve failed");
+            let deposit = build_message::<AgarioBuyinRef>(contract_account_id.clone())
+                .call(|contract| contract.deposit());
+            client
+                .call(&ink_e2e::alice(), deposit, 0, None)
+                .await
+                .expect("deposit failed");
+
+            // Then
+            let balance_of = build_message::<MockTokenRef>(token_account_id.clone())
+                .call(|token| token.balance_of(contract_account_id.clone()));
+            let balance = client
+                .call_dry_run(&ink_e2e::alice(), &balance_of, 0, None)
+                .await
+                .return_value();
+            assert_eq!(balance, 1000);
+
+            // A refund is claimed back through the token's transfer
+            let force_end_game = build_message::<AgarioBuyinRef>(contract_account_id.clone())
+                .call(|contract| contract.force_end_game());
+            client
+                .call(&ink_e2e::alice(), force_end_game, 0, None)
+                .await
+                .expect("force_end_game failed");
+            let claim_token = build_message::<AgarioBuyinRef>(contract_account_id.clone())
+                .call(|contract| contract.claim_token(token_account_id.clone()));
+            client
+                .call(&ink_e2e::alice(), claim_token, 0, None)
+                .await
+                .expect("claim_token failed");
+            let balance = client
+                .call_dry_run(&ink_e2e::alice(), &balance_of, 0, None)
+                .await
+                .return_value();
+            assert_eq!(balance, 0);
+
+            Ok(())
+        }
+
+        /// We test that a pegged buy-in is converted with the oracle's price at game start.
+        #[ink_e2e::test(additional_contracts = "../mock_oracle/Cargo.toml")]
+        async fn pegged_buy_in_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            use mock_oracle::MockOracleRef;
+
+            // Given
+            let oracle_account_id = client
+                .instantiate(
+                    "mock_oracle",
+                    &ink_e2e::alice(),
+                    MockOracleRef::new(3),
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let contract_account_id = client
+                .instantiate(
+                    "agario_buyin",
+                    &ink_e2e::alice(),
+                    AgarioBuyinRef::new(5),
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // When
+            let start_game =
+                build_message::<AgarioBuyinRef>(contract_account_id.clone()).call(|contract| {
+                    contract.start_game_with_config(GameConfig {
+                        min_players: 2,
+                        registration_period: 5,
+                        pegged_buy_in: Some(PeggedBuyIn {
+                            oracle: oracle_account_id.clone(),
+                            reference_amount: 1000,
+                            max_price_age: 60_000,
+                            slippage_bps: None,
+                        }),
+                        ..Default::default()
+                    })
+                });
+            client
+                .call(&ink_e2e::alice(), start_game, 0, None)
+                .await
+                .expect("start_game_with_config failed");
+
+            // Then
+            let get_buy_in = build_message::<AgarioBuyinRef>(contract_account_id.clone())
+                .call(|contract| contract.get_buy_in_amount());
+            let buy_in = client
+                .call_dry_run(&ink_e2e::alice(), &get_buy_in, 0, None)
+                .await
+                .return_value();
+            assert_eq!(buy_in, 3000);
+
+            Ok(())
+        }
     }
 }
@@ -0,0 +1,43 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Price oracle with a manually set price, used to test pegged buy-ins
+#[ink::contract]
+mod mock_oracle {
+    use price_oracle::PriceOracle;
+
+    #[ink(storage)]
+    pub struct MockOracle {
+        price: u128,
+        updated_at: Timestamp,
+    }
+
+    impl MockOracle {
+        #[ink(constructor)]
+        pub fn new(price: u128) -> Self {
+            Self {
+                price,
+                updated_at: Self::env().block_timestamp(),
+            }
+        }
+
+        /// Set the price as of the current block
+        #[ink(message)]
+        pub fn set_price(&mut self, price: u128) {
+            self.price = price;
+            self.updated_at = self.env().block_timestamp();
+        }
+
+        /// Backdate the last update to simulate a stale feed
+        #[ink(message)]
+        pub fn set_updated_at(&mut self, updated_at: Timestamp) {
+            self.updated_at = updated_at;
+        }
+    }
+
+    impl PriceOracle for MockOracle {
+        #[ink(message)]
+        fn latest_price(&self) -> (u128, u64) {
+            (self.price, self.updated_at)
+        }
+    }
+}
@@ -0,0 +1,122 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Minimal PSP22-style token used to test token-denominated games
+#[ink::contract]
+mod mock_token {
+    use ink::storage::Mapping;
+    use ink::H160;
+
+    #[ink(storage)]
+    pub struct MockToken {
+        total_supply: Balance,
+        balances: Mapping<H160, Balance>,
+        allowances: Mapping<(H160, H160), Balance>, // (owner, spender) -> allowance
+    }
+
+    /// Error types
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        InsufficientBalance,
+        InsufficientAllowance,
+    }
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    impl MockToken {
+        /// Mint the whole supply to the caller
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut balances = Mapping::default();
+            balances.insert(Self::env().caller(), &total_supply);
+            Self {
+                total_supply,
+                balances,
+                allowances: Mapping::default(),
+            }
+        }
+
+        #[ink(message)]
+        pub fn total_supply(&self) -> Balance {
+            self.total_supply
+        }
+
+        #[ink(message)]
+        pub fn balance_of(&self, owner: H160) -> Balance {
+            self.balances.get(owner).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        pub fn allowance(&self, owner: H160, spender: H160) -> Balance {
+            self.allowances.get((owner, spender)).unwrap_or(0)
+        }
+
+        /// Move tokens from the caller to `to`
+        #[ink(message)]
+        pub fn transfer(&mut self, to: H160, value: Balance) -> Result<()> {
+            let from = self.env().caller();
+            self.move_tokens(from, to, value)
+        }
+
+        /// Allow `spender` to move up to `value` of the caller's tokens
+        #[ink(message)]
+        pub fn approve(&mut self, spender: H160, value: Balance) -> Result<()> {
+            let owner = self.env().caller();
+            self.allowances.insert((owner, spender), &value);
+            Ok(())
+        }
+
+        /// Move tokens from `from` to `to` out of the caller's allowance
+        #[ink(message)]
+        pub fn transfer_from(&mut self, from: H160, to: H160, value: Balance) -> Result<()> {
+            let spender = self.env().caller();
+            let allowance = self.allowance(from, spender);
+            if allowance < value {
+                return Err(Error::InsufficientAllowance);
+            }
+            self.move_tokens(from, to, value)?;
+            self.allowances
+                .insert((from, spender), &allowance.saturating_sub(value));
+            Ok(())
+        }
+
+        /// Internal function moving tokens between balances
+        fn move_tokens(&mut self, from: H160, to: H160, value: Balance) -> Result<()> {
+            let from_balance = self.balance_of(from);
+            if from_balance < value {
+                return Err(Error::InsufficientBalance);
+            }
+            self.balances
+                .insert(from, &from_balance.saturating_sub(value));
+            let to_balance = self.balance_of(to);
+            self.balances.insert(to, &to_balance.saturating_add(value));
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /// Test transfer_from spends the allowance
+        #[ink::test]
+        fn transfer_from_spends_allowance() {
+            let owner = H160::from([1; 20]);
+            let spender = H160::from([2; 20]);
+            let receiver = H160::from([3; 20]);
+
+            ink::env::test::set_caller(owner);
+            let mut token = MockToken::new(1000);
+            token.approve(spender, 600).unwrap();
+
+            ink::env::test::set_caller(spender);
+            token.transfer_from(owner, receiver, 400).unwrap();
+            assert_eq!(token.balance_of(owner), 600);
+            assert_eq!(token.balance_of(receiver), 400);
+            assert_eq!(token.allowance(owner, spender), 200);
+
+            let result = token.transfer_from(owner, receiver, 400);
+            assert_eq!(result, Err(Error::InsufficientAllowance));
+        }
+    }
+}
@@ -0,0 +1,10 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Price feed used to peg buy-ins to a reference currency
+#[ink::trait_definition]
+pub trait PriceOracle {
+    /// Returns (price, updated_at): the native amount one reference currency unit costs and
+    /// the block timestamp (ms) the price was last updated at
+    #[ink(message)]
+    fn latest_price(&self) -> (u128, u64);
+}